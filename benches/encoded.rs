@@ -0,0 +1,85 @@
+//! Сравнение строкового и предразобранного путей на полном словаре: `cargo bench`.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use wordle_solver::{EncodedWord, load_dictionary, matches_encoded, matches_pattern, pattern_code, score_guess};
+
+fn time<T>(label: &str, run: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = run();
+    let elapsed = start.elapsed();
+    println!("{:<40} {:>10.1?}", label, elapsed);
+    (result, elapsed)
+}
+
+fn main() {
+    let dictionary = load_dictionary(Path::new(env!("CARGO_MANIFEST_DIR")).join("wordle-La.txt")).unwrap();
+    let encoded: Vec<EncodedWord> = dictionary.iter().map(|word| EncodedWord::new(word)).collect();
+    let guesses = &dictionary[..200];
+
+    println!(
+        "Фильтрация словаря ({} слов) по {} догадкам:",
+        dictionary.len(),
+        guesses.len()
+    );
+    let patterns: Vec<String> = guesses.iter().map(|guess| score_guess("crate", guess)).collect();
+    let (before, before_time) = time("  matches_pattern (строки)", || {
+        let mut kept = 0;
+        for (guess, pattern) in guesses.iter().zip(&patterns) {
+            kept += dictionary
+                .iter()
+                .filter(|word| matches_pattern(word, guess, pattern))
+                .count();
+        }
+        kept
+    });
+    let (after, after_time) = time("  matches_encoded", || {
+        let mut kept = 0;
+        for (guess, pattern) in guesses.iter().zip(&patterns) {
+            let guess = EncodedWord::new(guess);
+            let pattern: Vec<char> = pattern.chars().collect();
+            kept += encoded
+                .iter()
+                .filter(|word| matches_encoded(word, &guess, &pattern))
+                .count();
+        }
+        kept
+    });
+    assert_eq!(before, after);
+    println!(
+        "  ускорение: {:.1}x",
+        before_time.as_secs_f64() / after_time.as_secs_f64()
+    );
+
+    println!("Подсчёт шаблонов {} догадок на всём словаре:", guesses.len());
+    let (before, before_time) = time("  score_guess + HashMap<String>", || {
+        guesses
+            .iter()
+            .map(|guess| {
+                let mut buckets: HashMap<String, usize> = HashMap::new();
+                for answer in &dictionary {
+                    *buckets.entry(score_guess(answer, guess)).or_default() += 1;
+                }
+                buckets.len()
+            })
+            .sum::<usize>()
+    });
+    let (after, after_time) = time("  pattern_code", || {
+        guesses
+            .iter()
+            .map(|guess| {
+                let guess = EncodedWord::new(guess);
+                let mut codes: Vec<u64> = encoded.iter().map(|answer| pattern_code(answer, &guess)).collect();
+                codes.sort_unstable();
+                codes.dedup();
+                codes.len()
+            })
+            .sum::<usize>()
+    });
+    assert_eq!(before, after);
+    println!(
+        "  ускорение: {:.1}x",
+        before_time.as_secs_f64() / after_time.as_secs_f64()
+    );
+}
@@ -0,0 +1,61 @@
+use crate::pattern::score_guess;
+use crate::suggest::partition;
+
+/// Вариант Absurdle: ведущий ничего не загадывает заранее, а на каждую догадку отвечает шаблоном,
+/// при котором остаётся больше всего слов, и сужает список до этой группы.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Absurdle {
+    candidates: Vec<String>,
+    history: Vec<(String, String)>,
+}
+
+impl Absurdle {
+    pub fn new(answers: &[String]) -> Self {
+        Absurdle {
+            candidates: answers.to_vec(),
+            history: Vec::new(),
+        }
+    }
+
+    /// Слова, которые ведущий ещё может объявить ответом.
+    pub fn candidates(&self) -> &[String] {
+        &self.candidates
+    }
+
+    /// Сыгранные пары (догадка, шаблон).
+    pub fn history(&self) -> &[(String, String)] {
+        &self.history
+    }
+
+    /// Отвечает на догадку шаблоном самой большой группы и оставляет только её слова.
+    pub fn guess(&mut self, guess: &str) -> String {
+        let pattern = evil_pattern(guess, &self.candidates);
+        self.candidates.retain(|answer| score_guess(answer, guess) == pattern);
+        self.history.push((guess.to_string(), pattern.clone()));
+        pattern
+    }
+
+    /// Угадано ли слово последней догадкой (это возможно, только когда ведущему не осталось выбора).
+    pub fn is_won(&self) -> bool {
+        self.history
+            .last()
+            .is_some_and(|(_, pattern)| pattern.chars().all(|c| c == 'g'))
+    }
+}
+
+/// Шаблон, при котором среди `candidates` остаётся больше всего слов. При равенстве выбирается шаблон
+/// с меньшим числом зелёных, затем жёлтых букв (он меньше подсказывает), а затем первый по алфавиту.
+pub fn evil_pattern(guess: &str, candidates: &[String]) -> String {
+    let count = |pattern: &str, color: char| pattern.chars().filter(|&c| c == color).count();
+    partition(guess, candidates)
+        .into_iter()
+        .min_by(|(a, a_size), (b, b_size)| {
+            b_size
+                .cmp(a_size)
+                .then(count(a, 'g').cmp(&count(b, 'g')))
+                .then(count(a, 'y').cmp(&count(b, 'y')))
+                .then(a.cmp(b))
+        })
+        .map(|(pattern, _)| pattern)
+        .unwrap_or_else(|| "b".repeat(guess.chars().count()))
+}
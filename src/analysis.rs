@@ -0,0 +1,69 @@
+use crate::pattern::letters;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Частоты букв по списку слов: всего и по позициям.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct LetterFrequencies {
+    pub overall: BTreeMap<char, usize>,
+    pub positional: Vec<BTreeMap<char, usize>>,
+}
+
+impl LetterFrequencies {
+    /// Буквы по убыванию общего числа вхождений (при равенстве — по алфавиту).
+    pub fn sorted(&self) -> Vec<(char, usize)> {
+        let mut sorted: Vec<(char, usize)> = self.overall.iter().map(|(&letter, &count)| (letter, count)).collect();
+        sorted.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        sorted
+    }
+
+    /// Буквы на позиции `position` (с 0) по убыванию числа слов (при равенстве — по алфавиту).
+    pub fn ranked_at(&self, position: usize) -> Vec<(char, usize)> {
+        let mut ranked: Vec<(char, usize)> = self
+            .positional
+            .get(position)
+            .into_iter()
+            .flatten()
+            .map(|(&letter, &count)| (letter, count))
+            .collect();
+        ranked.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        ranked
+    }
+}
+
+/// Считает вхождения каждой буквы во всех словах и на каждой позиции.
+pub fn letter_frequencies(words: &[String]) -> LetterFrequencies {
+    let mut frequencies = LetterFrequencies::default();
+    for word in words {
+        for (i, letter) in word.chars().enumerate() {
+            *frequencies.overall.entry(letter).or_default() += 1;
+            if frequencies.positional.len() <= i {
+                frequencies.positional.resize(i + 1, BTreeMap::new());
+            }
+            *frequencies.positional[i].entry(letter).or_default() += 1;
+        }
+    }
+    frequencies
+}
+
+/// Гласные, которые `coverage_score` считает вдвойне: латинские и русские.
+const VOWELS: &str = "aeiouyаеёиоуыэюя";
+
+/// Сколько разных букв проверяет слово: каждая разная гласная — 2 очка, согласная — 1.
+/// Повторы букв ничего не добавляют. Простая замена энтропии для первых проб.
+pub fn coverage_score(word: &str) -> u32 {
+    let mut distinct = letters(word);
+    distinct.sort_unstable();
+    distinct.dedup();
+    distinct
+        .into_iter()
+        .map(|letter| if VOWELS.contains(letter) { 2 } else { 1 })
+        .sum()
+}
+
+/// Слова по убыванию `coverage_score`; при равенстве — в исходном порядке.
+pub fn rank_probes(words: &[String]) -> Vec<(&String, u32)> {
+    let mut ranked: Vec<(&String, u32)> = words.iter().map(|word| (word, coverage_score(word))).collect();
+    ranked.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+    ranked
+}
@@ -0,0 +1,49 @@
+use crate::solver::Solver;
+
+/// Несколько полей сразу, как в Quordle: у каждого поля свой загаданный ответ и свой список подходящих слов,
+/// а догадки общие. Поле решено, когда в его списке осталось одно слово.
+pub struct Boards {
+    boards: Vec<Solver>,
+}
+
+impl Boards {
+    /// `count` независимых полей с одинаковыми словарём и списком ответов.
+    pub fn new(count: usize, dictionary: &[String], answers: &[String]) -> Self {
+        let boards = (0..count)
+            .map(|_| Solver::with_answers(dictionary.to_vec(), answers.to_vec()))
+            .collect();
+        Boards { boards }
+    }
+
+    pub fn len(&self) -> usize {
+        self.boards.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.boards.is_empty()
+    }
+
+    /// Решатель поля `board` (с 0).
+    pub fn board(&self, board: usize) -> &Solver {
+        &self.boards[board]
+    }
+
+    pub fn is_solved(&self, board: usize) -> bool {
+        self.boards[board].filtered().len() == 1
+    }
+
+    /// Номера нерешённых полей по порядку: шаблон нужен только для них.
+    pub fn unsolved(&self) -> Vec<usize> {
+        (0..self.len()).filter(|&board| !self.is_solved(board)).collect()
+    }
+
+    /// Применяет общую догадку к полю `board` с шаблоном этого поля. Возвращает число оставшихся слов.
+    pub fn apply(&mut self, board: usize, guess: &str, pattern: &str) -> usize {
+        self.boards[board].apply(guess, pattern)
+    }
+
+    /// Сбрасывает все поля к исходному списку ответов.
+    pub fn reset(&mut self) {
+        self.boards.iter_mut().for_each(Solver::reset);
+    }
+}
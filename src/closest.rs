@@ -0,0 +1,55 @@
+use crate::pattern::{fold_letter, letters};
+
+/// Наибольшее расстояние Левенштейна, при котором слово словаря считается похожим на догадку.
+pub const MAX_EDIT_DISTANCE: usize = 2;
+
+/// Расстояние Левенштейна между словами: сколько букв нужно вставить, удалить или заменить.
+/// Буквы сравниваются без учёта регистра.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a = letters(a);
+    let b = letters(b);
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, &x) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, &y) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(x != y);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+/// До `max` слов словаря, ближайших к `guess` (расстояние не больше `MAX_EDIT_DISTANCE`): сначала самые
+/// близкие, при равном расстоянии — в порядке словаря. Само слово `guess` в ответ не попадает.
+pub fn closest_words(guess: &str, dict: &[String], max: usize) -> Vec<String> {
+    let length = guess.chars().count();
+    let mut scored: Vec<(usize, &String)> = dict
+        .iter()
+        .filter(|word| word.chars().count().abs_diff(length) <= MAX_EDIT_DISTANCE)
+        .map(|word| (edit_distance(guess, word), word))
+        .filter(|&(distance, _)| (1..=MAX_EDIT_DISTANCE).contains(&distance))
+        .collect();
+    scored.sort_by_key(|&(distance, _)| distance);
+    scored.into_iter().take(max).map(|(_, word)| word.clone()).collect()
+}
+
+/// Проверяет, что догадка есть в списке допустимых слов (`--strict-guess`); иначе в ошибке предлагает
+/// до `max` похожих слов.
+pub fn validate_known(guess: &str, dict: &[String], max: usize) -> Result<(), String> {
+    let folded = || guess.chars().map(fold_letter);
+    if dict.iter().any(|word| word.chars().map(fold_letter).eq(folded())) {
+        return Ok(());
+    }
+    let closest = closest_words(guess, dict, max);
+    if closest.is_empty() {
+        Err(format!("слова '{}' нет в списке допустимых догадок", guess))
+    } else {
+        Err(format!(
+            "слова '{}' нет в списке допустимых догадок; возможно, имелось в виду: {}",
+            guess,
+            closest.join(", ")
+        ))
+    }
+}
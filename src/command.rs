@@ -0,0 +1,182 @@
+use crate::pattern::{PatternMap, normalize_pattern_with, validate_guess, validate_pattern};
+
+/// Команда интерактивного режима.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InteractiveCommand<'a> {
+    /// Страница списка подходящих слов: пустой аргумент — первая, `more` — следующая, номер или `all`.
+    Show(&'a str),
+    New,
+    Suggest,
+    Hint,
+    /// Одна догадка по смешанной оценке энтропии и вероятности ответа (`--alpha`).
+    Best,
+    Undo,
+    Redo,
+    Known,
+    /// Введённые догадки и шаблоны по порядку.
+    History,
+    /// Где ещё может стоять каждая обязательная буква.
+    Positions,
+    Freq,
+    /// Самые вероятные буквы на каждой позиции среди подходящих слов.
+    Heatmap,
+    /// Сводка по подходящим словам.
+    Stats,
+    /// Случайное слово из подходящих.
+    Random,
+    /// Сколько подходящих слов с каждой буквой на позиции; пустой аргумент — первая позиция.
+    ByLetter(&'a str),
+    /// Слова с наибольшим охватом букв: среди подходящих или (`probe all`) во всём словаре.
+    Probe {
+        all: bool,
+    },
+    /// Вставка нескольких строк `догадка шаблон` (например, из сетки эмодзи) до пустой строки.
+    Paste,
+    Help,
+    Exit,
+    Split(&'a str),
+    /// Догадка под этим номером из последнего `suggest`.
+    Pick(&'a str),
+    /// Сколько слов в среднем останется после каждой из догадок (через пробел).
+    Expected(&'a str),
+    /// Почему слово подходит или не подходит под накопленные подсказки.
+    Explain(&'a str),
+    /// Подходит ли слово под все догадки (да или нет) и, если нет, какое правило нарушено первым.
+    Check(&'a str),
+    /// Пробная догадка `догадка шаблон`: сколько слов осталось бы, без изменения списка.
+    Try(&'a str),
+    /// Сколько догадок стратегии нужно, чтобы с нуля решить слово.
+    Difficulty(&'a str),
+    Regex(&'a str),
+    /// Подходящие слова, составленные ровно из этих букв.
+    Anagram(&'a str),
+    /// Быстрые фильтры по началу, концу или части слова.
+    Starts(&'a str),
+    Ends(&'a str),
+    Contains(&'a str),
+    Green(&'a str),
+    Yellow(&'a str),
+    Exclude(&'a str),
+    Include(&'a str),
+    Save(&'a str),
+    /// Отчёт о партии в Markdown.
+    Report(&'a str),
+    Remove(&'a str),
+    Add(&'a str),
+    SessionSave(&'a str),
+    SessionLoad(&'a str),
+    /// Не команда: догадка, возможно вместе с шаблоном через пробел (или после `guess`).
+    Guess(&'a str),
+}
+
+/// Разбирает строку интерактивного ввода. Имена команд не зависят от регистра; у частых команд есть
+/// сокращения: `s` — show, `n` — new, `q` и `quit` — exit, `?` — help. Пустая строка (в том числе конец
+/// ввода) означает выход. Всё, что не похоже на команду, считается догадкой; `guess <слово> [шаблон]` —
+/// догадка всегда, даже если слово совпадает с именем команды (`guess known`).
+pub fn parse_command(input: &str) -> InteractiveCommand<'_> {
+    parse_command_with(input, &PatternMap::default())
+}
+
+/// `parse_command` с символами цветов из `map`. Если после имени любой команды стоит шаблон длины этого имени
+/// (`green ybbbg`, `yellow bbgybg`, `explain bgybbgy`), строка считается догадкой с шаблоном, а не командой.
+pub fn parse_command_with<'a>(input: &'a str, map: &PatternMap) -> InteractiveCommand<'a> {
+    use InteractiveCommand::*;
+
+    let input = input.trim();
+    let (keyword, argument) = match input.split_once(char::is_whitespace) {
+        Some((keyword, argument)) => (keyword, argument.trim()),
+        None => (input, ""),
+    };
+    if is_pattern_for(keyword, argument, map) {
+        return Guess(input);
+    }
+    match (keyword.to_lowercase().as_str(), argument) {
+        ("", _) | ("exit" | "quit" | "q", "") => Exit,
+        ("show" | "s", "") => Show(""),
+        ("new" | "n", "") => New,
+        ("suggest", "") => Suggest,
+        ("hint", "") => Hint,
+        ("best", "") => Best,
+        ("undo", "") => Undo,
+        ("redo", "") => Redo,
+        ("known", "") => Known,
+        ("history", "") => History,
+        ("positions", "") => Positions,
+        ("freq", "") => Freq,
+        ("heatmap", "") => Heatmap,
+        ("stats", "") => Stats,
+        ("random", "") => Random,
+        ("byletter", "") => ByLetter(""),
+        ("probe", "") => Probe { all: false },
+        ("paste", "") => Paste,
+        ("pick", "") => Pick(""),
+        ("help" | "?", "") => Help,
+        (_, "") => Guess(input),
+        ("guess", word) => Guess(word),
+        ("probe", argument) if argument.eq_ignore_ascii_case("all") => Probe { all: true },
+        ("show" | "s", page)
+            if page.eq_ignore_ascii_case("more")
+                || page.eq_ignore_ascii_case("all")
+                || page.chars().all(|c| c.is_ascii_digit()) =>
+        {
+            Show(page)
+        }
+        ("byletter", position) => ByLetter(position),
+        ("split", word) => Split(word),
+        ("pick", number) => Pick(number),
+        ("expected", words) => Expected(words),
+        ("explain", word) => Explain(word),
+        ("check", word) => Check(word),
+        ("try", guess) => Try(guess),
+        ("difficulty", word) => Difficulty(word),
+        ("regex", expression) => Regex(expression),
+        ("anagram", letters) => Anagram(letters),
+        ("starts", prefix) => Starts(prefix),
+        ("ends", suffix) => Ends(suffix),
+        ("contains", substring) => Contains(substring),
+        ("green", placement) => Green(placement),
+        ("yellow", placement) => Yellow(placement),
+        ("exclude", letters) => Exclude(letters),
+        ("include", letters) => Include(letters),
+        ("save", path) => Save(path),
+        ("report", path) => Report(path),
+        ("remove", word) => Remove(word),
+        ("add", word) => Add(word),
+        ("session", argument) => match argument.split_once(char::is_whitespace) {
+            Some((action, path)) if action.eq_ignore_ascii_case("save") => SessionSave(path.trim()),
+            Some((action, path)) if action.eq_ignore_ascii_case("load") => SessionLoad(path.trim()),
+            _ => Guess(input),
+        },
+        _ => Guess(input),
+    }
+}
+
+/// Похож ли `argument` на шаблон для слова `word`: той же длины и только из g/y/b после перевода из `map`.
+/// Согласованность шаблона здесь не проверяется — это дело `parse_guess_input`.
+fn is_pattern_for(word: &str, argument: &str, map: &PatternMap) -> bool {
+    let pattern = normalize_pattern_with(argument, map);
+    !pattern.is_empty()
+        && pattern.chars().count() == word.chars().count()
+        && pattern
+            .chars()
+            .all(|c| matches!(c.to_ascii_lowercase(), 'g' | 'y' | 'b'))
+}
+
+/// Разбирает догадку из интерактивного ввода: слово и, если он указан через пробел, шаблон (в нотации `map`).
+/// Догадка не той длины, что слова словаря (`length`), и неверный шаблон отклоняются с объяснением, чтобы
+/// не применять их к списку: иначе под такую догадку не подошло бы ни одно слово.
+pub fn parse_guess_input<'a>(
+    input: &'a str,
+    length: usize,
+    map: &PatternMap,
+) -> Result<(&'a str, Option<String>), String> {
+    let (guess, pattern) = match input.split_once(char::is_whitespace) {
+        Some((guess, pattern)) => (guess, Some(normalize_pattern_with(pattern.trim(), map))),
+        None => (input, None),
+    };
+    validate_guess(guess, length)?;
+    if let Some(pattern) = &pattern {
+        validate_pattern(guess, pattern)?;
+    }
+    Ok((guess, pattern))
+}
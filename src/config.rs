@@ -0,0 +1,56 @@
+use crate::dictionary::EMBEDDED_PREFIX;
+use serde::Deserialize;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Имя файла настроек.
+pub const CONFIG_FILE: &str = "wordle-solver.toml";
+
+/// Значения по умолчанию из `wordle-solver.toml`. Флаги командной строки их перекрывают, а они — встроенные
+/// значения по умолчанию. `color` и `strategy` записываются так же, как значения `--color` и `--strategy`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub dictionary: Option<String>,
+    pub answers: Option<String>,
+    pub frequency: Option<String>,
+    pub color: Option<String>,
+    pub strategy: Option<String>,
+}
+
+impl Config {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        toml::from_str(text).map_err(|e| e.message().to_string())
+    }
+
+    /// Читает файл настроек. Относительные пути к словарям считаются от каталога, где лежит файл.
+    pub fn load<P: AsRef<Path>>(filename: P) -> io::Result<Self> {
+        let filename = filename.as_ref();
+        let mut config =
+            Config::parse(&fs::read_to_string(filename)?).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        if let Some(base) = filename.parent() {
+            for path in [&mut config.dictionary, &mut config.answers, &mut config.frequency]
+                .into_iter()
+                .flatten()
+            {
+                if !path.starts_with(EMBEDDED_PREFIX) && Path::new(path).is_relative() {
+                    *path = base.join(&*path).to_string_lossy().into_owned();
+                }
+            }
+        }
+        Ok(config)
+    }
+
+    /// Где искать файл настроек: `wordle-solver.toml` в текущем каталоге, иначе в `$XDG_CONFIG_HOME`
+    /// (по умолчанию `~/.config`). `None`, если файла нет ни там, ни там.
+    pub fn find() -> Option<PathBuf> {
+        let config_home = std::env::var_os("XDG_CONFIG_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".config")));
+        std::iter::once(PathBuf::from(CONFIG_FILE))
+            .chain(config_home.map(|dir| dir.join(CONFIG_FILE)))
+            .find(|path| path.is_file())
+    }
+}
@@ -0,0 +1,278 @@
+use crate::encoded::EncodedWord;
+use crate::pattern::{LetterCase, is_color, letter_count, revealed_count};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Всё, что известно о загаданном слове после нескольких догадок.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Constraints {
+    greens: Vec<Option<char>>,
+    not_at: Vec<BTreeSet<char>>,
+    min_counts: BTreeMap<char, usize>,
+    max_counts: BTreeMap<char, usize>,
+    case: LetterCase,
+}
+
+impl Constraints {
+    pub fn new() -> Self {
+        Constraints::default()
+    }
+
+    /// Пустые ограничения, буквы в которых сравниваются по правилу `case`.
+    pub fn with_case(case: LetterCase) -> Self {
+        Constraints {
+            case,
+            ..Constraints::default()
+        }
+    }
+
+    /// Строит ограничения по истории пар (догадка, шаблон).
+    pub fn from_history(history: &[(String, String)]) -> Self {
+        Constraints::from_history_with(history, LetterCase::Insensitive)
+    }
+
+    /// `from_history` со сравнением букв по правилу `case`.
+    pub fn from_history_with(history: &[(String, String)], case: LetterCase) -> Self {
+        let mut constraints = Constraints::with_case(case);
+        for (guess, pattern) in history {
+            constraints.add(guess, pattern);
+        }
+        constraints
+    }
+
+    /// Как сравниваются буквы этих ограничений.
+    pub fn case(&self) -> LetterCase {
+        self.case
+    }
+
+    /// Добавляет сведения из очередной догадки и шаблона.
+    pub fn add(&mut self, guess: &str, pattern: &str) {
+        let guess_chars = self.case.letters(guess);
+        let pattern_chars: Vec<char> = pattern.chars().collect();
+        self.resize(guess_chars.len());
+
+        for (i, (&letter, &color)) in guess_chars.iter().zip(&pattern_chars).enumerate() {
+            if is_color(color, 'g') {
+                self.greens[i] = Some(letter);
+            } else {
+                self.not_at[i].insert(letter);
+            }
+
+            let revealed = revealed_count(&guess_chars, &pattern_chars, letter, self.case);
+            let min = self.min_counts.entry(letter).or_default();
+            *min = (*min).max(revealed);
+            if is_color(color, 'b') {
+                let max = self.max_counts.entry(letter).or_insert(revealed);
+                *max = (*max).min(revealed);
+            }
+        }
+    }
+
+    /// Отмечает, что буквы `letter` нет в слове.
+    pub fn exclude(&mut self, letter: char) {
+        self.max_counts.insert(self.case.fold(letter), 0);
+    }
+
+    /// Отмечает, что буква `letter` есть в слове (хотя бы одна).
+    pub fn include(&mut self, letter: char) {
+        let min = self.min_counts.entry(self.case.fold(letter)).or_default();
+        *min = (*min).max(1);
+    }
+
+    /// Ставит букву `letter` зелёной на позицию `position` (с 0) в слове из `length` букв.
+    pub fn set_green(&mut self, position: usize, letter: char, length: usize) {
+        self.resize(length.max(position + 1));
+        self.greens[position] = Some(self.case.fold(letter));
+        self.include(letter);
+    }
+
+    /// Отмечает букву `letter` жёлтой на позиции `position` (с 0): она есть в слове, но не здесь.
+    pub fn add_yellow(&mut self, position: usize, letter: char, length: usize) {
+        self.resize(length.max(position + 1));
+        self.not_at[position].insert(self.case.fold(letter));
+        self.include(letter);
+    }
+
+    fn resize(&mut self, length: usize) {
+        if self.greens.len() < length {
+            self.greens.resize(length, None);
+            self.not_at.resize(length, BTreeSet::new());
+        }
+    }
+
+    /// Известные зелёные буквы по позициям.
+    pub fn greens(&self) -> &[Option<char>] {
+        &self.greens
+    }
+
+    /// Буквы, которые точно есть в слове, с минимальным числом вхождений.
+    pub fn min_counts(&self) -> &BTreeMap<char, usize> {
+        &self.min_counts
+    }
+
+    /// Буквы с известным максимальным числом вхождений (0 — буквы нет в слове).
+    pub fn max_counts(&self) -> &BTreeMap<char, usize> {
+        &self.max_counts
+    }
+
+    /// Буквы, которых точно нет на позиции `position`.
+    pub fn not_at(&self, position: usize) -> Option<&BTreeSet<char>> {
+        self.not_at.get(position)
+    }
+
+    /// Позиции (с 0) в слове из `length` букв, где ещё может стоять буква `letter`: её зелёные позиции и
+    /// свободные от зелёных, на которых она не была жёлтой или чёрной. Если все копии буквы уже зелёные
+    /// (максимум вхождений известен), остаются только зелёные позиции.
+    pub fn possible_positions(&self, letter: char, length: usize) -> Vec<usize> {
+        let green_at = |i: usize| self.greens.get(i).copied().flatten();
+        let greens = (0..length).filter(|&i| green_at(i) == Some(letter)).count();
+        let more = self.max_counts.get(&letter).is_none_or(|&max| max > greens);
+        (0..length)
+            .filter(|&i| match green_at(i) {
+                Some(green) => green == letter,
+                None => more && self.not_at(i).is_none_or(|not_at| !not_at.contains(&letter)),
+            })
+            .collect()
+    }
+
+    /// Обязательные буквы (зелёные и жёлтые).
+    pub fn required(&self) -> impl Iterator<Item = char> + '_ {
+        self.min_counts
+            .iter()
+            .filter(|&(_, &count)| count > 0)
+            .map(|(&letter, _)| letter)
+    }
+
+    /// Исключённые буквы (чёрные без зелёных и жёлтых копий).
+    pub fn excluded(&self) -> impl Iterator<Item = char> + '_ {
+        self.max_counts
+            .iter()
+            .filter(|&(_, &count)| count == 0)
+            .map(|(&letter, _)| letter)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == Constraints::with_case(self.case)
+    }
+
+    /// Подходит ли слово под все накопленные ограничения.
+    pub fn matches(&self, word: &str) -> bool {
+        self.matches_encoded(&EncodedWord::with_case(word, self.case))
+    }
+
+    /// То же, что `matches`, для заранее разобранного слова.
+    pub fn matches_encoded(&self, word: &EncodedWord) -> bool {
+        if self.excluded().any(|letter| word.contains(letter)) {
+            return false;
+        }
+
+        let word_chars = word.chars();
+        if !self.greens.is_empty() && word_chars.len() != self.greens.len() {
+            return false;
+        }
+
+        for ((letter, green), not_at) in word_chars.iter().zip(&self.greens).zip(&self.not_at) {
+            if green.is_some_and(|green| green != *letter) || not_at.contains(letter) {
+                return false;
+            }
+        }
+
+        self.min_counts
+            .iter()
+            .all(|(&letter, &min)| letter_count(word_chars, letter, self.case) >= min)
+            && self
+                .max_counts
+                .iter()
+                .all(|(&letter, &max)| letter_count(word_chars, letter, self.case) <= max)
+    }
+
+    /// Какое правило первым отсекает слово: `"green"` (зелёные буквы и длина), `"yellow"` (буквы не на своих
+    /// местах и обязательные буквы) или `"black"` (исключённые и лишние буквы). `None`, если слово подходит.
+    /// Для отладочных сводок фильтра; сам фильтр использует `matches_encoded`.
+    pub(crate) fn failed_stage(&self, word: &EncodedWord) -> Option<&'static str> {
+        let word_chars = word.chars();
+        let greens_fail = (!self.greens.is_empty() && word_chars.len() != self.greens.len())
+            || word_chars
+                .iter()
+                .zip(&self.greens)
+                .any(|(letter, green)| green.is_some_and(|green| green != *letter));
+        if greens_fail {
+            return Some("green");
+        }
+        let yellows_fail = word_chars
+            .iter()
+            .zip(&self.not_at)
+            .any(|(letter, not_at)| not_at.contains(letter))
+            || self
+                .min_counts
+                .iter()
+                .any(|(&letter, &min)| letter_count(word_chars, letter, self.case) < min);
+        if yellows_fail {
+            return Some("yellow");
+        }
+        let blacks_fail = self
+            .max_counts
+            .iter()
+            .any(|(&letter, &max)| letter_count(word_chars, letter, self.case) > max);
+        blacks_fail.then_some("black")
+    }
+
+    /// То же, что `matches`, но вместо `false` объясняет первое нарушенное ограничение.
+    pub fn match_reason(&self, word: &str) -> Result<(), String> {
+        let word_chars = self.case.letters(word);
+        if !self.greens.is_empty() && word_chars.len() != self.greens.len() {
+            return Err(format!(
+                "в слове {} букв, а нужно {}",
+                word_chars.len(),
+                self.greens.len()
+            ));
+        }
+
+        for (i, ((&letter, green), not_at)) in word_chars.iter().zip(&self.greens).zip(&self.not_at).enumerate() {
+            if let Some(green) = green.filter(|&green| green != letter) {
+                return Err(format!(
+                    "позиция {}: должна быть зелёная {}, а стоит {}",
+                    i + 1,
+                    green.to_uppercase(),
+                    letter.to_uppercase()
+                ));
+            }
+            if not_at.contains(&letter) {
+                return Err(format!(
+                    "позиция {}: буквы {} здесь быть не может",
+                    i + 1,
+                    letter.to_uppercase()
+                ));
+            }
+        }
+
+        for (&letter, &min) in &self.min_counts {
+            match letter_count(&word_chars, letter, self.case) {
+                count if count >= min => {}
+                0 => return Err(format!("нет обязательной буквы {}", letter.to_uppercase())),
+                count => {
+                    return Err(format!(
+                        "буква {} нужна минимум {} раз(а), а в слове {}",
+                        letter.to_uppercase(),
+                        min,
+                        count
+                    ));
+                }
+            }
+        }
+        for (&letter, &max) in &self.max_counts {
+            let count = letter_count(&word_chars, letter, self.case);
+            if count > 0 && max == 0 {
+                return Err(format!("есть исключённая буква {}", letter.to_uppercase()));
+            }
+            if count > max {
+                return Err(format!(
+                    "буква {} встречается {} раз(а), а можно не больше {}",
+                    letter.to_uppercase(),
+                    count,
+                    max
+                ));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+use crate::pattern::{LetterCase, is_color, letters, revealed_count};
+use std::collections::BTreeMap;
+
+/// Ищет прямые противоречия в истории догадок: две разные зелёные буквы на одной позиции, зелёная буква там,
+/// где она же раньше была отмечена не на своём месте, и буква, которая одновременно обязательна и исключена
+/// (или нужна чаще, чем разрешено). Ходы в сообщениях нумеруются с 1.
+pub fn diagnose_empty(history: &[(String, String)]) -> Vec<String> {
+    let mut greens: BTreeMap<usize, (char, usize)> = BTreeMap::new();
+    let mut not_at: BTreeMap<(usize, char), usize> = BTreeMap::new();
+    let mut min_counts: BTreeMap<char, (usize, usize)> = BTreeMap::new();
+    let mut max_counts: BTreeMap<char, (usize, usize)> = BTreeMap::new();
+    let mut length = 0;
+
+    for (turn, (guess, pattern)) in history.iter().enumerate() {
+        let turn = turn + 1;
+        let guess_chars = letters(guess);
+        let pattern_chars: Vec<char> = pattern.chars().collect();
+        length = length.max(guess_chars.len());
+
+        for (i, (&letter, &color)) in guess_chars.iter().zip(&pattern_chars).enumerate() {
+            if is_color(color, 'g') {
+                greens.entry(i).or_insert((letter, turn));
+            } else {
+                not_at.entry((i, letter)).or_insert(turn);
+            }
+
+            let revealed = revealed_count(&guess_chars, &pattern_chars, letter, LetterCase::Insensitive);
+            let min = min_counts.entry(letter).or_insert((0, turn));
+            if revealed > min.0 {
+                *min = (revealed, turn);
+            }
+            if is_color(color, 'b') {
+                let max = max_counts.entry(letter).or_insert((revealed, turn));
+                if revealed < max.0 {
+                    *max = (revealed, turn);
+                }
+            }
+        }
+    }
+
+    let mut findings = Vec::new();
+    for (turn, (guess, pattern)) in history.iter().enumerate() {
+        for (i, (letter, color)) in letters(guess).into_iter().zip(pattern.chars()).enumerate() {
+            if !is_color(color, 'g') {
+                continue;
+            }
+            let (first, first_turn) = greens[&i];
+            if first != letter {
+                findings.push(format!(
+                    "позиция {}: в ходе {} зелёная {}, а в ходе {} зелёная {}",
+                    i + 1,
+                    first_turn,
+                    first.to_uppercase(),
+                    turn + 1,
+                    letter.to_uppercase()
+                ));
+            }
+            if let Some(&excluded_turn) = not_at.get(&(i, letter)) {
+                findings.push(format!(
+                    "позиция {}: буква {} зелёная в ходе {}, но в ходе {} отмечена не на своём месте",
+                    i + 1,
+                    letter.to_uppercase(),
+                    turn + 1,
+                    excluded_turn
+                ));
+            }
+        }
+    }
+
+    for (&letter, &(min, min_turn)) in &min_counts {
+        if let Some(&(max, max_turn)) = max_counts.get(&letter)
+            && min > max
+        {
+            findings.push(if max == 0 {
+                format!(
+                    "буква {} найдена в ходе {}, но в ходе {} отмечена как отсутствующая",
+                    letter.to_uppercase(),
+                    min_turn,
+                    max_turn
+                )
+            } else {
+                format!(
+                    "буква {}: в ходе {} нужна минимум {} раз(а), а в ходе {} — не больше {}",
+                    letter.to_uppercase(),
+                    min_turn,
+                    min,
+                    max_turn,
+                    max
+                )
+            });
+        }
+    }
+
+    let required: usize = min_counts.values().map(|&(min, _)| min).sum();
+    if required > length {
+        findings.push(format!(
+            "обязательных букв ({}) больше, чем букв в слове ({})",
+            required, length
+        ));
+    }
+
+    findings.dedup();
+    findings
+}
@@ -0,0 +1,217 @@
+use std::collections::{HashMap, HashSet};
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs::File;
+use std::io::BufRead;
+#[cfg(not(target_arch = "wasm32"))]
+use std::io::{self, Write};
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+
+/// Словари, встроенные в бинарник: (имя, содержимое).
+pub const EMBEDDED_DICTIONARIES: &[(&str, &str)] = &[("wordle-La", include_str!("../wordle-La.txt"))];
+
+/// Префикс, по которому `--dictionary` выбирает встроенный словарь вместо файла.
+pub const EMBEDDED_PREFIX: &str = "embedded:";
+
+#[cfg(not(target_arch = "wasm32"))]
+/// Путь, по которому `load_dictionary` читает словарь со стандартного ввода (`--dictionary -`).
+pub const STDIN_PATH: &str = "-";
+
+/// Как приводить строки словаря к словам.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DictionaryOptions {
+    /// Не переводить слова в нижний регистр.
+    pub keep_case: bool,
+    /// Оставлять слова с цифрами, знаками препинания и пробелами.
+    pub allow_nonalpha: bool,
+}
+
+/// Приводит строки словаря к словам: убирает BOM и пробелы по краям, пропускает пустые строки,
+/// переводит в нижний регистр (если не задан `keep_case`) и убирает повторы, сохраняя первое вхождение.
+/// Слова не только из букв (`char::is_alphabetic`) пропускаются, если не задан `allow_nonalpha`.
+/// Возвращает слова и число пропущенных из-за этого строк.
+pub fn normalize_dictionary(
+    lines: impl IntoIterator<Item = String>,
+    options: DictionaryOptions,
+) -> (Vec<String>, usize) {
+    let mut seen = HashSet::new();
+    let mut words = Vec::new();
+    let mut rejected = 0;
+    for (i, line) in lines.into_iter().enumerate() {
+        let line = if i == 0 {
+            line.trim_start_matches('\u{feff}')
+        } else {
+            &line
+        };
+        let word = line.trim();
+        if word.is_empty() {
+            continue;
+        }
+        if !options.allow_nonalpha && !word.chars().all(char::is_alphabetic) {
+            rejected += 1;
+            continue;
+        }
+        let word = normalize_case(word, options);
+        if seen.insert(word.clone()) {
+            words.push(word);
+        }
+    }
+    (words, rejected)
+}
+
+/// Слово в нижнем регистре, если не задан `keep_case`.
+fn normalize_case(word: &str, options: DictionaryOptions) -> String {
+    if options.keep_case {
+        word.to_string()
+    } else {
+        word.to_lowercase()
+    }
+}
+
+/// Разбивает текст словаря на слова, по одному на строку.
+pub fn parse_dictionary(text: &str) -> Vec<String> {
+    parse_dictionary_with(text, DictionaryOptions::default()).0
+}
+
+/// Как `parse_dictionary`, с настройками; возвращает также число пропущенных строк не из букв.
+pub fn parse_dictionary_with(text: &str, options: DictionaryOptions) -> (Vec<String>, usize) {
+    normalize_dictionary(text.lines().map(str::to_string), options)
+}
+
+/// Объединяет несколько словарей в один: сначала слова первого, затем новые слова следующих, без повторов.
+pub fn merge_dictionaries(lists: impl IntoIterator<Item = Vec<String>>) -> Vec<String> {
+    let mut seen = HashSet::new();
+    lists
+        .into_iter()
+        .flatten()
+        .filter(|word| seen.insert(word.clone()))
+        .collect()
+}
+
+/// Встроенный словарь по имени.
+pub fn embedded_dictionary(name: &str) -> Option<Vec<String>> {
+    EMBEDDED_DICTIONARIES
+        .iter()
+        .find(|(embedded, _)| *embedded == name)
+        .map(|(_, text)| parse_dictionary(text))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load_dictionary<P: AsRef<Path>>(filename: P) -> io::Result<Vec<String>> {
+    Ok(load_dictionary_with(filename, DictionaryOptions::default())?.0)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// Как `load_dictionary`, с настройками; возвращает также число пропущенных строк не из букв.
+/// Путь `-` (`STDIN_PATH`) значит стандартный ввод.
+pub fn load_dictionary_with<P: AsRef<Path>>(
+    filename: P,
+    options: DictionaryOptions,
+) -> io::Result<(Vec<String>, usize)> {
+    let filename = filename.as_ref();
+    let reader: Box<dyn BufRead> = if filename == Path::new(STDIN_PATH) {
+        Box::new(io::stdin().lock())
+    } else {
+        open_text(filename)?
+    };
+    let lines = reader.lines().collect::<io::Result<Vec<String>>>()?;
+    Ok(normalize_dictionary(lines, options))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// Первые байты файла, сжатого gzip.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+#[cfg(not(target_arch = "wasm32"))]
+/// Открывает текстовый файл; сжатый gzip (распознаётся по заголовку) распаковывается на лету с фичей `gzip`.
+fn open_text(path: &Path) -> io::Result<Box<dyn BufRead>> {
+    let mut reader = io::BufReader::new(File::open(path)?);
+    if !reader.fill_buf()?.starts_with(&GZIP_MAGIC) {
+        return Ok(Box::new(reader));
+    }
+
+    #[cfg(feature = "gzip")]
+    return Ok(Box::new(io::BufReader::new(flate2::bufread::MultiGzDecoder::new(
+        reader,
+    ))));
+    #[cfg(not(feature = "gzip"))]
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "файл сжат gzip: соберите программу с фичей gzip",
+    ))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// Записывает слова в файл, по одному на строку (в формате, который читает `load_dictionary`).
+pub fn save_words<P: AsRef<Path>>(filename: P, words: &[impl AsRef<str>]) -> io::Result<()> {
+    let mut writer = io::BufWriter::new(File::create(filename)?);
+    for word in words {
+        writeln!(writer, "{}", word.as_ref())?;
+    }
+    writer.flush()
+}
+
+/// Частоты слов из файла вида `слово число` (по одной паре на строку).
+/// Возвращает таблицу частот и номера (с 1) строк, которые не удалось разобрать; пустые строки пропускаются.
+pub fn parse_frequencies(reader: impl BufRead) -> (HashMap<String, u64>, Vec<usize>) {
+    parse_frequencies_with(reader, DictionaryOptions::default())
+}
+
+/// Как `parse_frequencies`, но слова приводятся к виду слов словаря с теми же настройками (`normalize_dictionary`):
+/// без BOM и, если не задан `keep_case`, в нижнем регистре, чтобы `Apple 10` нашло слово `apple`.
+pub fn parse_frequencies_with(reader: impl BufRead, options: DictionaryOptions) -> (HashMap<String, u64>, Vec<usize>) {
+    let mut frequencies = HashMap::new();
+    let mut malformed = Vec::new();
+    for (i, line) in reader.lines().map_while(Result::ok).enumerate() {
+        let line = if i == 0 {
+            line.trim_start_matches('\u{feff}')
+        } else {
+            &line
+        };
+        let mut fields = line.split_whitespace();
+        match (fields.next(), fields.next().map(str::parse::<u64>), fields.next()) {
+            (None, _, _) => {}
+            (Some(word), Some(Ok(count)), None) => {
+                frequencies.insert(normalize_case(word, options), count);
+            }
+            _ => malformed.push(i + 1),
+        }
+    }
+    (frequencies, malformed)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load_frequencies<P: AsRef<Path>>(filename: P) -> io::Result<(HashMap<String, u64>, Vec<usize>)> {
+    load_frequencies_with(filename, DictionaryOptions::default())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// Как `load_frequencies`, с настройками словаря (см. `parse_frequencies_with`).
+pub fn load_frequencies_with<P: AsRef<Path>>(
+    filename: P,
+    options: DictionaryOptions,
+) -> io::Result<(HashMap<String, u64>, Vec<usize>)> {
+    let file = File::open(filename)?;
+    Ok(parse_frequencies_with(io::BufReader::new(file), options))
+}
+
+/// Самая частая длина слова в словаре (при равенстве — меньшая).
+pub fn modal_length(words: &[String]) -> Option<usize> {
+    modal_length_of(words.iter().map(String::as_str))
+}
+
+pub(crate) fn modal_length_of<'a>(words: impl Iterator<Item = &'a str>) -> Option<usize> {
+    let mut counts: HashMap<usize, usize> = HashMap::new();
+    for word in words {
+        *counts.entry(word.chars().count()).or_default() += 1;
+    }
+    counts
+        .into_iter()
+        .max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(&a.0)))
+        .map(|(length, _)| length)
+}
+
+/// Оставляет в словаре только слова длины `length`.
+pub fn filter_by_length(words: &mut Vec<String>, length: usize) {
+    words.retain(|word| word.chars().count() == length);
+}
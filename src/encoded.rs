@@ -0,0 +1,105 @@
+use crate::pattern::LetterCase;
+
+/// Слово, заранее разобранное на буквы, с маской присутствующих букв a–z.
+///
+/// Строится один раз на слово словаря, чтобы многократные проверки и подсчёт шаблонов
+/// не разбирали строку заново.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodedWord {
+    chars: Vec<char>,
+    mask: u32,
+    case: LetterCase,
+}
+
+/// Бит буквы в маске: `a` — младший бит. Для букв вне a–z маски нет (0).
+pub fn letter_bit(letter: char) -> u32 {
+    if letter.is_ascii_lowercase() {
+        1 << (letter as u32 - 'a' as u32)
+    } else {
+        0
+    }
+}
+
+impl EncodedWord {
+    /// Разбирает слово; буквы приводятся к нижнему регистру (`fold_letter`).
+    pub fn new(word: &str) -> Self {
+        EncodedWord::with_case(word, LetterCase::Insensitive)
+    }
+
+    /// Разбирает слово для сравнения по правилу `case`: с `Sensitive` регистр букв сохраняется.
+    pub fn with_case(word: &str, case: LetterCase) -> Self {
+        let chars = case.letters(word);
+        let mask = chars.iter().fold(0, |mask, &letter| mask | letter_bit(letter));
+        EncodedWord { chars, mask, case }
+    }
+
+    pub fn chars(&self) -> &[char] {
+        &self.chars
+    }
+
+    pub fn mask(&self) -> u32 {
+        self.mask
+    }
+
+    pub fn case(&self) -> LetterCase {
+        self.case
+    }
+
+    pub fn len(&self) -> usize {
+        self.chars.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.chars.is_empty()
+    }
+
+    /// Есть ли буква в слове (по правилу `case`); для a–z проверяется по маске.
+    pub fn contains(&self, letter: char) -> bool {
+        let letter = self.case.fold(letter);
+        match letter_bit(letter) {
+            0 => self.chars.contains(&letter),
+            bit => self.mask & bit != 0,
+        }
+    }
+}
+
+impl std::fmt::Display for EncodedWord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.chars.iter().try_for_each(|letter| write!(f, "{}", letter))
+    }
+}
+
+/// Наибольшая длина слова, для которой шаблон помещается в числовой код `pattern_code`.
+pub const MAX_CODED_LEN: usize = 40;
+
+/// Шаблон, который Wordle выдаст на `guess` при ответе `answer`, в виде числа в троичной записи
+/// (позиция i — разряд 3^i: 0 — b, 1 — y, 2 — g). Те же правила, что и в `score_guess`,
+/// но без выделения памяти. Слова должны быть не длиннее `MAX_CODED_LEN`.
+pub fn pattern_code(answer: &EncodedWord, guess: &EncodedWord) -> u64 {
+    let answer_chars = answer.chars();
+    let guess_chars = guess.chars();
+    debug_assert!(guess_chars.len() <= MAX_CODED_LEN);
+
+    let mut used: u64 = 0;
+    for i in 0..guess_chars.len().min(answer_chars.len()) {
+        if guess_chars[i] == answer_chars[i] {
+            used |= 1 << i;
+        }
+    }
+    let greens = used;
+
+    let mut code = 0;
+    let mut place = 1;
+    for (i, &letter) in guess_chars.iter().enumerate() {
+        if greens & (1 << i) != 0 {
+            code += 2 * place;
+        } else if answer.contains(letter)
+            && let Some(j) = (0..answer_chars.len()).find(|&j| used & (1 << j) == 0 && answer_chars[j] == letter)
+        {
+            used |= 1 << j;
+            code += place;
+        }
+        place *= 3;
+    }
+    code
+}
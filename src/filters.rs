@@ -0,0 +1,46 @@
+use crate::dictionary::parse_dictionary;
+use crate::pattern::{self, matches_pattern, normalize_pattern};
+use regex::Regex;
+
+/// Компилирует регулярное выражение, привязанное к слову целиком (`^(?:...)$`).
+pub fn word_regex(pattern: &str) -> Result<Regex, regex::Error> {
+    Regex::new(&format!("^(?:{})$", pattern))
+}
+
+/// Слова из текста словаря (по одному на строку), подходящие под одну догадку и её шаблон.
+/// Шаблон нормализуется, как в командной строке: можно g/y/b, цифры 0/1/2 или эмодзи.
+pub fn filter_words(dictionary: &str, guess: &str, pattern: &str) -> Vec<String> {
+    let guess = guess.to_lowercase();
+    let pattern = normalize_pattern(pattern);
+    let mut words = parse_dictionary(dictionary);
+    words.retain(|word| matches_pattern(word, &guess, &pattern));
+    words
+}
+
+/// Составлено ли слово ровно из букв `letters` (тот же набор с повторами, без учёта регистра и порядка).
+pub fn is_anagram(word: &str, letters: &str) -> bool {
+    let mut word = pattern::letters(word);
+    let mut expected = pattern::letters(letters);
+    word.sort_unstable();
+    expected.sort_unstable();
+    word == expected
+}
+
+/// Где искать буквы в быстрых фильтрах `starts`, `ends` и `contains` (и `--starts`, `--ends`, `--contains`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fragment {
+    Prefix,
+    Suffix,
+    Substring,
+}
+
+impl Fragment {
+    /// Начинается ли слово с `letters`, заканчивается ли ими или содержит их подряд.
+    pub fn matches(self, word: &str, letters: &str) -> bool {
+        match self {
+            Fragment::Prefix => word.starts_with(letters),
+            Fragment::Suffix => word.ends_with(letters),
+            Fragment::Substring => word.contains(letters),
+        }
+    }
+}
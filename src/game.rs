@@ -0,0 +1,144 @@
+use crate::pattern::{LetterCase, score_guess_with};
+use crate::simulate::MAX_GUESSES;
+
+/// Партия против загаданного слова: догадки и шаблоны, которые выдал бы Wordle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    answer: String,
+    history: Vec<(String, String)>,
+    case: LetterCase,
+    max_guesses: usize,
+}
+
+impl Game {
+    pub fn new(answer: &str) -> Self {
+        Game::with_case(answer, LetterCase::Insensitive)
+    }
+
+    /// Партия, в которой шаблоны считаются со сравнением букв по правилу `case` (`--case-sensitive`).
+    pub fn with_case(answer: &str, case: LetterCase) -> Self {
+        Game {
+            answer: answer.to_string(),
+            history: Vec::new(),
+            case,
+            max_guesses: MAX_GUESSES,
+        }
+    }
+
+    /// Задаёт число попыток в партии (`--max-guesses`); по умолчанию их `MAX_GUESSES`.
+    pub fn set_max_guesses(&mut self, max_guesses: usize) {
+        self.max_guesses = max_guesses;
+    }
+
+    /// Сколько попыток в партии.
+    pub fn max_guesses(&self) -> usize {
+        self.max_guesses
+    }
+
+    pub fn answer(&self) -> &str {
+        &self.answer
+    }
+
+    /// Сыгранные пары (догадка, шаблон).
+    pub fn history(&self) -> &[(String, String)] {
+        &self.history
+    }
+
+    /// Играет догадку и возвращает её шаблон.
+    pub fn guess(&mut self, guess: &str) -> String {
+        let pattern = score_guess_with(&self.answer, guess, self.case);
+        self.history.push((guess.to_string(), pattern.clone()));
+        pattern
+    }
+
+    /// Угадано ли слово последней догадкой.
+    pub fn is_won(&self) -> bool {
+        self.history
+            .last()
+            .is_some_and(|(_, pattern)| pattern.chars().all(|c| c == 'g'))
+    }
+
+    /// Партия окончена: слово угадано или догадки кончились.
+    pub fn is_over(&self) -> bool {
+        self.is_won() || self.history.len() >= self.max_guesses
+    }
+}
+
+/// Перемешивает число (splitmix64), чтобы соседние зёрна давали далёкие друг от друга слова.
+fn mix(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Загаданное слово для зерна `seed`: одно и то же при одинаковых зерне и списке ответов.
+pub fn pick_answer(answers: &[String], seed: u64) -> Option<&str> {
+    if answers.is_empty() {
+        return None;
+    }
+    Some(&answers[(mix(seed) % answers.len() as u64) as usize])
+}
+
+/// Генератор псевдослучайных чисел (splitmix64) для команды `random`: одно и то же зерно даёт одну и ту же
+/// последовательность слов.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let value = mix(self.state);
+        self.state = self.state.wrapping_add(1);
+        value
+    }
+
+    /// Случайное слово из `words`; `None`, если список пуст.
+    pub fn pick<'a>(&mut self, words: &'a [String]) -> Option<&'a str> {
+        if words.is_empty() {
+            return None;
+        }
+        Some(&words[(self.next_u64() % words.len() as u64) as usize])
+    }
+}
+
+/// Номер дня от 1970-01-01 для даты по григорианскому календарю.
+pub fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Разбирает дату вида `ГГГГ-ММ-ДД` в номер дня от 1970-01-01.
+pub fn parse_date(date: &str) -> Result<i64, String> {
+    let invalid = || format!("дата '{}' должна иметь вид ГГГГ-ММ-ДД", date);
+    let mut fields = date.split('-');
+    let (Some(year), Some(month), Some(day), None) = (fields.next(), fields.next(), fields.next(), fields.next())
+    else {
+        return Err(invalid());
+    };
+    let year: i64 = year.parse().map_err(|_| invalid())?;
+    let month: u32 = month.parse().map_err(|_| invalid())?;
+    let day: u32 = day.parse().map_err(|_| invalid())?;
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let month_days = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => return Err(format!("в дате '{}' нет месяца {}", date, month)),
+    };
+    if day == 0 || day > month_days {
+        return Err(format!("в дате '{}' нет дня {}", date, day));
+    }
+    Ok(days_from_civil(year, month, day))
+}
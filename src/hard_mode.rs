@@ -0,0 +1,27 @@
+use crate::pattern::{LetterCase, is_color, letter_count, letters, revealed_count};
+
+/// Проверяет догадку по правилам сложного режима: открытые зелёные буквы остаются на своих местах,
+/// а все найденные буквы (зелёные и жёлтые) используются снова. Возвращает причину, если догадка недопустима.
+pub fn violates_hard_mode(guess: &str, history: &[(String, String)]) -> Option<String> {
+    let guess_chars = letters(guess);
+
+    for (past_guess, past_pattern) in history {
+        let past_chars = letters(past_guess);
+        let pattern_chars: Vec<char> = past_pattern.chars().collect();
+
+        for (i, (&letter, &color)) in past_chars.iter().zip(&pattern_chars).enumerate() {
+            if is_color(color, 'g') && guess_chars.get(i) != Some(&letter) {
+                return Some(format!("{}-я буква должна быть {}", i + 1, letter.to_uppercase()));
+            }
+        }
+
+        for &letter in &past_chars {
+            let required = revealed_count(&past_chars, &pattern_chars, letter, LetterCase::Insensitive);
+            if letter_count(&guess_chars, letter, LetterCase::Insensitive) < required {
+                return Some(format!("догадка должна содержать {}", letter.to_uppercase()));
+            }
+        }
+    }
+
+    None
+}
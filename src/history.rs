@@ -0,0 +1,32 @@
+use crate::pattern::{PatternMap, normalize_pattern_with, validate_guess, validate_pattern};
+use std::io::BufRead;
+
+/// Пары (догадка, шаблон) из текста со строками вида `догадка шаблон`; пустые строки пропускаются.
+/// Шаблоны нормализуются и проверяются так же, как при вводе вручную.
+/// При первой неверной строке возвращает ошибку с её номером (с 1).
+pub fn parse_history(reader: impl BufRead, length: usize) -> Result<Vec<(String, String)>, String> {
+    parse_history_with(reader, length, &PatternMap::default())
+}
+
+/// `parse_history` с символами цветов шаблона из `map`.
+pub fn parse_history_with(
+    reader: impl BufRead,
+    length: usize,
+    map: &PatternMap,
+) -> Result<Vec<(String, String)>, String> {
+    let mut history = Vec::new();
+    for (i, line) in reader.lines().enumerate() {
+        let line = line.map_err(|e| format!("строка {}: {}", i + 1, e))?;
+        let mut fields = line.split_whitespace();
+        let (guess, pattern) = match (fields.next(), fields.next(), fields.next()) {
+            (None, _, _) => continue,
+            (Some(guess), Some(pattern), None) => (guess, normalize_pattern_with(pattern, map)),
+            _ => return Err(format!("строка {}: ожидается «догадка шаблон»", i + 1)),
+        };
+        validate_guess(guess, length)
+            .and_then(|_| validate_pattern(guess, &pattern))
+            .map_err(|e| format!("строка {}: {}", i + 1, e))?;
+        history.push((guess.to_string(), pattern));
+    }
+    Ok(history)
+}
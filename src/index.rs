@@ -0,0 +1,127 @@
+use crate::constraints::Constraints;
+use crate::encoded::EncodedWord;
+use crate::pattern::LetterCase;
+use std::collections::HashMap;
+
+/// Множество номеров слов в виде битовой маски.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct WordSet {
+    bits: Vec<u64>,
+}
+
+impl WordSet {
+    fn empty(len: usize) -> Self {
+        WordSet {
+            bits: vec![0; len.div_ceil(64)],
+        }
+    }
+
+    fn full(len: usize) -> Self {
+        let mut set = WordSet::empty(len);
+        (0..len).for_each(|id| set.insert(id));
+        set
+    }
+
+    fn insert(&mut self, id: usize) {
+        self.bits[id / 64] |= 1 << (id % 64);
+    }
+
+    pub(crate) fn contains(&self, id: usize) -> bool {
+        self.bits[id / 64] & (1 << (id % 64)) != 0
+    }
+
+    fn intersect(&mut self, other: Option<&WordSet>) {
+        match other {
+            Some(other) => self.bits.iter_mut().zip(&other.bits).for_each(|(a, b)| *a &= b),
+            None => self.bits.fill(0),
+        }
+    }
+
+    fn subtract(&mut self, other: Option<&WordSet>) {
+        if let Some(other) = other {
+            self.bits.iter_mut().zip(&other.bits).for_each(|(a, b)| *a &= !b);
+        }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.bits.iter().enumerate().flat_map(|(block, &bits)| {
+            (0..64)
+                .filter(move |bit| bits & (1 << bit) != 0)
+                .map(move |bit| block * 64 + bit)
+        })
+    }
+}
+
+/// Обратный индекс по списку слов: для каждой пары (позиция, буква) и для каждой буквы — множество слов,
+/// где она встречается. Строится один раз на список; по ограничениям сначала отсекает слова пересечением
+/// и вычитанием множеств, а точную проверку (число повторов букв, длину) делает только для оставшихся.
+#[derive(Debug, Clone)]
+pub struct WordIndex {
+    words: Vec<EncodedWord>,
+    at: HashMap<(usize, char), WordSet>,
+    has: HashMap<char, WordSet>,
+}
+
+impl WordIndex {
+    pub fn new(words: &[String]) -> Self {
+        WordIndex::with_case(words, LetterCase::Insensitive)
+    }
+
+    /// Индекс, в котором буквы слов сравниваются по правилу `case`.
+    pub fn with_case(words: &[String], case: LetterCase) -> Self {
+        let words: Vec<EncodedWord> = words.iter().map(|word| EncodedWord::with_case(word, case)).collect();
+        let mut at = HashMap::new();
+        let mut has = HashMap::new();
+        for (id, word) in words.iter().enumerate() {
+            for (position, &letter) in word.chars().iter().enumerate() {
+                at.entry((position, letter))
+                    .or_insert_with(|| WordSet::empty(words.len()))
+                    .insert(id);
+                has.entry(letter)
+                    .or_insert_with(|| WordSet::empty(words.len()))
+                    .insert(id);
+            }
+        }
+        WordIndex { words, at, has }
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    pub(crate) fn word(&self, id: usize) -> &EncodedWord {
+        &self.words[id]
+    }
+
+    /// Слова, которые не отсекаются индексом: точная проверка `Constraints::matches_encoded` ещё нужна.
+    pub(crate) fn narrow(&self, constraints: &Constraints) -> WordSet {
+        let mut set = WordSet::full(self.len());
+        for (position, green) in constraints.greens().iter().enumerate() {
+            if let Some(letter) = green {
+                set.intersect(self.at.get(&(position, *letter)));
+            }
+            for letter in constraints.not_at(position).into_iter().flatten() {
+                set.subtract(self.at.get(&(position, *letter)));
+            }
+        }
+        for letter in constraints.required() {
+            set.intersect(self.has.get(&letter));
+        }
+        for letter in constraints.excluded() {
+            set.subtract(self.has.get(&letter));
+        }
+        set
+    }
+
+    /// Номера слов, подходящих под `constraints`, по возрастанию.
+    pub fn candidates(&self, constraints: &Constraints) -> Vec<usize> {
+        self.narrow(constraints)
+            .iter()
+            .filter(|&id| constraints.matches_encoded(&self.words[id]))
+            .collect()
+    }
+}
@@ -0,0 +1,80 @@
+mod absurdle;
+mod analysis;
+mod boards;
+mod closest;
+mod command;
+#[cfg(not(target_arch = "wasm32"))]
+mod config;
+mod constraints;
+mod diagnose;
+mod dictionary;
+mod encoded;
+mod filters;
+mod game;
+mod hard_mode;
+mod history;
+mod index;
+#[cfg(all(feature = "mmap", not(target_arch = "wasm32")))]
+mod mapped;
+mod opener;
+mod output;
+mod pattern;
+mod session;
+mod simulate;
+mod solver;
+mod suggest;
+#[cfg(feature = "wasm")]
+mod wasm;
+
+pub use absurdle::{Absurdle, evil_pattern};
+pub use analysis::{LetterFrequencies, coverage_score, letter_frequencies, rank_probes};
+pub use boards::Boards;
+pub use closest::{MAX_EDIT_DISTANCE, closest_words, edit_distance, validate_known};
+pub use command::{InteractiveCommand, parse_command, parse_command_with, parse_guess_input};
+#[cfg(not(target_arch = "wasm32"))]
+pub use config::{CONFIG_FILE, Config};
+pub use constraints::Constraints;
+pub use diagnose::diagnose_empty;
+pub use dictionary::{
+    DictionaryOptions, EMBEDDED_DICTIONARIES, EMBEDDED_PREFIX, embedded_dictionary, filter_by_length,
+    merge_dictionaries, modal_length, normalize_dictionary, parse_dictionary, parse_dictionary_with, parse_frequencies,
+    parse_frequencies_with,
+};
+#[cfg(not(target_arch = "wasm32"))]
+pub use dictionary::{
+    STDIN_PATH, load_dictionary, load_dictionary_with, load_frequencies, load_frequencies_with, save_words,
+};
+pub use encoded::{EncodedWord, MAX_CODED_LEN, letter_bit, pattern_code};
+pub use filters::{Fragment, filter_words, is_anagram, word_regex};
+pub use game::{Game, Rng, days_from_civil, parse_date, pick_answer};
+pub use hard_mode::violates_hard_mode;
+pub use history::{parse_history, parse_history_with};
+pub use index::WordIndex;
+#[cfg(all(feature = "mmap", not(target_arch = "wasm32")))]
+pub use mapped::MappedWords;
+pub use opener::{Opener, OpenerCache, dictionary_hash};
+pub use output::{
+    FilterResult, emoji_grid, emoji_row, format_attempts, format_blended, format_boards, format_by_letter,
+    format_check, format_difficulty, format_elimination, format_explanation, format_frequencies, format_heatmap,
+    format_history, format_known, format_page, format_positions, format_preview, format_removed, format_report,
+    format_solution, format_split, format_stats, format_suggestions, format_uncertainty, page_count, render_colored,
+};
+pub use pattern::{
+    LetterCase, PatternMap, check_blacks, check_greens, check_yellows, fold_letter, match_reason, match_reason_with,
+    matches_encoded, matches_pattern, matches_pattern_with, normalize_pattern, normalize_pattern_with, parse_placement,
+    parse_position, score_encoded, score_guess, score_guess_with, validate_guess, validate_letters, validate_pattern,
+    validate_pattern_with,
+};
+pub use session::{SESSION_VERSION, Session};
+pub use simulate::{
+    Difficulty, MAX_GUESSES, SimulationStats, is_solved, play, play_on, sample, simulate, simulate_from, simulate_on,
+    simulate_with,
+};
+pub use solver::Solver;
+pub use suggest::{
+    BlendedGuess, CoverageSuggestion, ENTROPY_EPSILON, PARALLEL_THRESHOLD, Strategy, Suggestion, SuggestionList,
+    coverage_suggestions, entropy, entropy_with, expected_remaining, expected_remaining_with, partition,
+    partition_with, suggest_entropy, suggest_entropy_with, suggest_minimax, suggest_minimax_with,
+    suggest_weighted_entropy, suggest_weighted_entropy_with, weighted_entropy, weighted_entropy_with, worst_case,
+    worst_case_with,
+};
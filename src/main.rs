@@ -1,185 +1,2518 @@
-use clap::Parser;
-use std::fs::File;
-use std::io::{self, BufRead, Write};
-use std::path::Path;
+use clap::parser::ValueSource;
+use clap::{ArgMatches, CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
+use clap_complete::Shell;
+use std::cell::OnceCell;
+use std::collections::hash_map::RandomState;
+use std::fs::{self, File};
+use std::hash::BuildHasher;
+use std::io::{self, BufWriter, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+use wordle_solver::{
+    Absurdle, Boards, Config, DictionaryOptions, EMBEDDED_DICTIONARIES, EMBEDDED_PREFIX, FilterResult, Fragment, Game,
+    InteractiveCommand, LetterCase, MAX_GUESSES, Opener, OpenerCache, PatternMap, Rng, STDIN_PATH, Session,
+    SimulationStats, Solver, Strategy, SuggestionList, coverage_suggestions, diagnose_empty, embedded_dictionary,
+    emoji_grid, expected_remaining_with, filter_by_length, format_attempts, format_blended, format_boards,
+    format_by_letter, format_check, format_difficulty, format_elimination, format_explanation, format_frequencies,
+    format_heatmap, format_history, format_known, format_page, format_positions, format_preview, format_removed,
+    format_report, format_solution, format_split, format_stats, format_suggestions, format_uncertainty, is_anagram,
+    is_solved, letter_frequencies, load_dictionary_with, load_frequencies_with, merge_dictionaries, modal_length,
+    normalize_pattern_with, page_count, parse_command_with, parse_date, parse_dictionary, parse_guess_input,
+    parse_history_with, parse_placement, parse_position, partition_with, pick_answer, play_on, rank_probes,
+    render_colored, sample, save_words, score_guess_with, simulate_on, validate_guess, validate_known,
+    validate_letters, validate_pattern, validate_pattern_with, violates_hard_mode, word_regex,
+};
+#[cfg(feature = "mmap")]
+use wordle_solver::{Constraints, MappedWords};
+
+mod profile;
+#[cfg(feature = "server")]
+mod server;
+#[cfg(feature = "tui")]
+mod tui;
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    Text,
+    Plain,
+    Json,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum ColorMode {
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorMode {
+    fn enabled(self) -> bool {
+        match self {
+            ColorMode::Auto => io::stdout().is_terminal(),
+            ColorMode::Always => true,
+            ColorMode::Never => false,
+        }
+    }
+}
 
 #[derive(Parser)]
 #[command(
     name = "wordle-solver",
     about = "Решатель Wordle: фильтрует слова по подсказкам.",
     long_about = "Решатель Wordle. Позволяет фильтровать слова по догадке и шаблону (pattern).\n\
-    В интерактивном режиме поддерживаются команды: show, new, exit.\n\
-    Формат pattern: строка из символов g (green), y (yellow), b (black), например: ybbgy."
+    В интерактивном режиме поддерживаются команды: show, new, suggest, pick, hint, best, undo, redo, known, history, positions, freq, heatmap, stats, random, byletter, probe, paste, split, expected, try, explain, check, difficulty, regex, anagram, starts, ends, contains, green, yellow, exclude, include, save, report, remove, add, session, guess, help, exit.\n\
+    Формат pattern: строка из символов g (green), y (yellow), b (black), например: ybbgy, или цифр 2/1/0."
 )]
 struct Args {
-    #[arg(short, long, default_value = "wordle-La.txt")]
-    dictionary: String,
-    #[arg(short, long, requires = "pattern", help = "Догадка (слово, например: crate)")]
-    guess: Option<String>,
+    #[arg(
+        short,
+        long,
+        help = "Файл словаря (по умолчанию wordle-La.txt, а если его нет — встроенный словарь).\n\
+        Встроенный словарь можно выбрать явно: embedded:<имя>, а «-» читает словарь со стандартного ввода.\n\
+        Если указать несколько раз, словари объединятся без повторов"
+    )]
+    dictionary: Vec<String>,
+
+    #[arg(long, help = "Показать встроенные словари и выйти")]
+    list_embedded: bool,
+
+    #[arg(
+        short,
+        long,
+        help = "Файл со списком возможных ответов. Тогда --dictionary задаёт допустимые догадки:\n\
+        фильтруются ответы, а подсказки выбираются из всех допустимых догадок"
+    )]
+    answers: Option<String>,
+
+    #[arg(
+        long,
+        help = "Не переводить слова словаря в нижний регистр (пробелы по краям, пустые строки\n\
+        и повторы убираются всегда)"
+    )]
+    keep_case: bool,
+
+    #[arg(
+        long,
+        help = "Различать регистр при сравнении букв: US и us — разные слова (включает --keep-case)"
+    )]
+    case_sensitive: bool,
+
+    #[arg(long, help = "Не пропускать слова словаря с цифрами, знаками препинания и пробелами")]
+    allow_nonalpha: bool,
+
+    #[arg(
+        long,
+        help = "Файл частот слов (строки вида «слово число»): show выводит слова по убыванию частоты,\n\
+        а при равных подсказках выбирается более частое слово"
+    )]
+    frequency: Option<String>,
+    #[arg(
+        short,
+        long,
+        requires = "pattern",
+        help = "Догадка (слово, например: crate). Можно повторять вместе с --pattern:\n\
+        пары применяются по порядку"
+    )]
+    guess: Vec<String>,
     #[arg(
         short,
         long,
         requires = "guess",
         help = "Шаблон результата (pattern): строка из символов g (green), y (yellow), b (black).\n\
-        Пример: ybbgy"
+        Можно вставить строку эмодзи из общей сетки: 🟩 🟨 ⬛/⬜ или цифры 2/1/0. Пример: ybbgy"
+    )]
+    pattern: Vec<String>,
+
+    #[arg(
+        long,
+        value_name = "ЦВЕТ=СИМВОЛ,...",
+        default_value = "green=g,yellow=y,black=b",
+        help = "Свои символы цветов в шаблонах, например green=+,yellow=?,black=. или green=з,yellow=ж,black=ч.\n\
+        Символы не должны совпадать; эмодзи и цифры 2/1/0 понимаются всегда"
+    )]
+    pattern_map: PatternMap,
+
+    #[arg(
+        long,
+        conflicts_with_all = ["guess", "interactive"],
+        help = "Читать пары «догадка шаблон» со стандартного ввода, по одной на строку, до конца ввода"
+    )]
+    stdin: bool,
+
+    #[arg(
+        long,
+        value_name = "ПУТЬ",
+        conflicts_with_all = ["guess", "stdin"],
+        help = "Повторить пары «догадка шаблон» из файла (по одной на строку, как в --stdin), показывая, сколько\n\
+        слов осталось после каждой; затем вывести результат или, с --interactive, продолжить партию"
+    )]
+    replay: Option<String>,
+
+    #[arg(
+        short,
+        long,
+        conflicts_with = "interactive",
+        help = "Записать подходящие слова в файл, по одному на строку"
+    )]
+    output: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "ПОЗИЦИЯ:БУКВА",
+        help = "Зелёная буква на позиции (с 1), например 3:a. Можно повторять"
+    )]
+    green: Vec<String>,
+
+    #[arg(
+        long,
+        value_name = "ПОЗИЦИЯ:БУКВА",
+        help = "Жёлтая буква: есть в слове, но не на этой позиции (с 1), например 1:s. Можно повторять"
+    )]
+    yellow: Vec<String>,
+
+    #[arg(long, help = "Буквы, которых точно нет в слове (например: xqz)")]
+    exclude: Option<String>,
+
+    #[arg(long, help = "Буквы, которые точно есть в слове (например: ae)")]
+    include: Option<String>,
+
+    #[arg(long, value_name = "БУКВЫ", help = "Оставить слова, которые начинаются с этих букв")]
+    starts: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "БУКВЫ",
+        help = "Оставить слова, которые заканчиваются этими буквами"
+    )]
+    ends: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "БУКВЫ",
+        help = "Оставить слова, в которых подряд встречаются эти буквы"
+    )]
+    contains: Option<String>,
+
+    #[arg(
+        short,
+        long,
+        help = "Длина слова: из словаря берутся только слова этой длины.\n\
+        По умолчанию — самая частая длина в словаре"
+    )]
+    length: Option<usize>,
+
+    #[arg(
+        short,
+        long,
+        help = "Включить интерактивный режим. Пары --guess/--pattern, если заданы, применяются до первого ввода"
+    )]
+    interactive: bool,
+
+    #[arg(short, long, help = "Показать лучшие следующие догадки (сколько — см. --top)")]
+    suggest: bool,
+
+    #[arg(long, default_value_t = SUGGEST_TOP, help = "Сколько догадок показывать в подсказках и в команде probe")]
+    top: usize,
+
+    #[arg(
+        long,
+        value_name = "N",
+        default_value_t = MAX_CANDIDATES_FOR_SUGGEST,
+        help = "Если подходящих слов больше N, подсказки (suggest, hint, report, /suggest у --serve) выбираются\n\
+        по охвату букв без полного подсчёта энтропии (suggest и hint сначала берут кэш первых догадок);\n\
+        0 — считать всегда"
     )]
-    pattern: Option<String>,
+    max_candidates_for_suggest: usize,
+
+    #[arg(long, help = "Показать одну лучшую следующую догадку")]
+    hint: bool,
+
+    #[arg(
+        long,
+        help = "Подсказывать только слова, которые ещё могут быть ответом. Такая догадка даёт меньше\n\
+        информации, чем лучшая из всего словаря, зато может сразу оказаться верной"
+    )]
+    answers_only: bool,
+
+    #[arg(
+        long,
+        help = "При выходе вывести на stderr, сколько времени заняли загрузка словаря,\n\
+        применение догадок и подсчёт подсказок за всю сессию или пакетный запуск"
+    )]
+    profile: bool,
+
+    #[arg(
+        long,
+        help = "В пакетном режиме перед результатом вывести на stderr применяемые догадки и шаблоны"
+    )]
+    echo_history: bool,
+
+    #[arg(
+        long,
+        value_name = "K",
+        help = "После каждой догадки показать до K убранных ею слов и правило, по которому убрано каждое\n\
+        (в пакетном режиме — на stderr): чтобы проверить, что шаблон введён верно"
+    )]
+    show_removed: Option<usize>,
+
+    #[arg(
+        long,
+        help = "Взвешивать энтропию частотами из --frequency: вероятным ответом считается частое слово,\n\
+        и подсказки лучше разделяют частые слова. Без файла частот энтропия обычная"
+    )]
+    weighted: bool,
+
+    #[arg(
+        long,
+        conflicts_with_all = ["interactive", "guess", "stdin", "simulate", "play", "auto", "absurdle", "boards", "serve", "tui", "mmap"],
+        help = "Посчитать лучшую первую догадку и 10 лучших догадок по всему словарю и записать их в кэш\n\
+        (см. --opener-cache). Потом hint и suggest на нетронутом словаре берут их из кэша"
+    )]
+    compute_opener: bool,
+
+    #[arg(
+        long,
+        value_name = "ПУТЬ",
+        help = "Файл кэша первых догадок (по умолчанию ~/.wordle_solver_opener.json).\n\
+        Запись в нём действует, пока не изменились словарь, ответы и частоты слов"
+    )]
+    opener_cache: Option<String>,
+
+    #[arg(
+        long,
+        default_value = "entropy",
+        help = "Стратегия подсказок и самоигры: entropy (наибольшая энтропия)\n\
+        или minimax (наименьшая наибольшая группа)"
+    )]
+    strategy: Strategy,
+
+    #[arg(long, help = "Показать частоты букв среди подходящих слов (всего и по позициям)")]
+    freq: bool,
+
+    #[arg(
+        short,
+        long,
+        value_enum,
+        default_value = "text",
+        help = "Формат вывода в пакетном режиме: text, plain (только слова, по одному на строку)\n\
+        или json ({\"count\": N, \"words\": [...]})"
+    )]
+    format: OutputFormat,
+
+    #[arg(
+        long,
+        conflicts_with_all = ["format", "suggest", "hint", "freq", "interactive"],
+        help = "Напечатать только число подходящих слов"
+    )]
+    count: bool,
+
+    #[arg(
+        long,
+        conflicts_with = "interactive",
+        help = "Завершиться с кодом 2, если подходящих слов не осталось"
+    )]
+    fail_on_empty: bool,
+
+    #[arg(
+        long,
+        conflicts_with_all = ["interactive", "tui"],
+        help = "Не переходить в интерактивный режим, если не задан ни один фильтр, а завершиться с ошибкой.\n\
+        Для скриптов: без аргументов программа не будет ждать ввода"
+    )]
+    no_interactive_fallback: bool,
+
+    #[arg(
+        long,
+        value_enum,
+        default_value = "auto",
+        help = "Подсветка догадки цветами Wordle: auto (только в терминале), always или never"
+    )]
+    color: ColorMode,
+
+    #[arg(
+        long,
+        help = "Сложный режим: каждая догадка обязана использовать все найденные подсказки"
+    )]
+    hard: bool,
+
+    #[arg(
+        long,
+        help = "Принимать только догадки из словаря; для неизвестного слова предлагаются похожие\n\
+        (отличающиеся не больше чем на 2 буквы)"
+    )]
+    strict_guess: bool,
+
+    #[arg(
+        long,
+        default_value_t = 1.0,
+        value_parser = parse_alpha,
+        help = "Вес энтропии в команде best: оценка = alpha × энтропия + (1 − alpha) × вероятность ответа.\n\
+        1 — только информация (как hint), 0 — только самое вероятное из подходящих слов"
+    )]
+    alpha: f64,
+
+    #[arg(
+        long,
+        group = "self_play",
+        help = "Самоигра: решить каждое слово словаря и показать статистику числа догадок"
+    )]
+    simulate: bool,
+
+    #[arg(
+        long,
+        requires = "simulate",
+        help = "Сыграть только N слов, равномерно выбранных из словаря"
+    )]
+    sample: Option<usize>,
+
+    #[arg(
+        long,
+        requires = "self_play",
+        help = "В самоигре (--simulate) печатать сетку эмодзи каждой партии, в --auto — сетку сыгранной партии,\n\
+        как в Wordle"
+    )]
+    emoji: bool,
+
+    #[arg(
+        long,
+        value_name = "ПУТЬ",
+        requires = "simulate",
+        help = "Записать результат каждой партии самоигры в CSV: answer,guesses,solved.\n\
+        Строки пишутся по мере игры, так что файл можно смотреть, не дожидаясь конца"
+    )]
+    bench_csv: Option<String>,
+
+    #[arg(
+        long,
+        conflicts_with_all = ["interactive", "simulate", "guess", "stdin"],
+        help = "Игра: программа загадывает слово из списка ответов, а вы отгадываете за 6 попыток.\n\
+        По умолчанию слово дня (по сегодняшней дате)"
+    )]
+    play: bool,
+
+    #[arg(
+        long,
+        conflicts_with = "date",
+        help = "Зерно N: в игре выбрать загаданное слово по нему, в интерактивном режиме —\n\
+        воспроизводимая последовательность слов команды random"
+    )]
+    seed: Option<u64>,
+
+    #[arg(long, requires = "play", help = "Выбрать слово дня для даты ГГГГ-ММ-ДД")]
+    date: Option<String>,
+
+    #[arg(long, requires = "play", help = "Показать загаданное слово в начале игры")]
+    cheat: bool,
+
+    #[arg(
+        long,
+        requires = "answer",
+        group = "self_play",
+        conflicts_with_all = ["interactive", "simulate", "play", "guess", "stdin"],
+        help = "Сыграть партию самостоятельно против слова из --answer и показать каждый ход"
+    )]
+    auto: bool,
+
+    #[arg(long, requires = "auto", help = "Загаданное слово для --auto")]
+    answer: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "СЛОВО",
+        help = "В --simulate и --auto всегда начинать с этой догадки, а дальше играть по стратегии.\n\
+        В --simulate можно указать несколько раз: статистика выводится для каждой первой догадки"
+    )]
+    first_guess: Vec<String>,
+
+    #[arg(
+        long,
+        value_name = "СЛОВО",
+        conflicts_with_all = ["interactive", "simulate", "play", "auto", "guess", "stdin"],
+        help = "Трудность слова: за сколько догадок стратегия --strategy решает его с нетронутого поля\n\
+        (до 3 — лёгкое, 4 — среднее, больше — трудное), и сами догадки"
+    )]
+    difficulty: Option<String>,
+
+    #[arg(
+        long,
+        conflicts_with_all = ["interactive", "simulate", "play", "auto", "boards", "guess", "stdin"],
+        help = "Absurdle: программа не загадывает слово, а на каждую догадку отвечает шаблоном,\n\
+        при котором остаётся больше всего слов"
+    )]
+    absurdle: bool,
+
+    #[arg(
+        long,
+        value_name = "K",
+        conflicts_with_all = ["interactive", "simulate", "play", "auto", "guess", "stdin"],
+        help = "Несколько полей сразу, как в Quordle (K = 4): догадка общая, а шаблон вводится для каждого\n\
+        нерешённого поля. Поле решено, когда в его списке осталось одно слово"
+    )]
+    boards: Option<usize>,
+
+    #[arg(
+        long,
+        conflicts_with_all = ["interactive", "simulate", "play", "auto", "absurdle", "boards", "guess", "stdin"],
+        help = "(фича server) HTTP-сервер: POST /filter и POST /suggest принимают JSON\n\
+        {\"dictionary\": [...], \"guesses\": [{\"guess\": ..., \"pattern\": ...}]}"
+    )]
+    serve: bool,
+
+    #[arg(
+        long,
+        requires = "serve",
+        default_value_t = 3000,
+        help = "Порт HTTP-сервера (слушает 127.0.0.1)"
+    )]
+    port: u16,
+
+    #[arg(
+        long,
+        conflicts_with_all = ["simulate", "play", "auto", "absurdle", "boards", "guess", "stdin"],
+        help = "(фича tui) Полноэкранный интерактивный режим: сетка догадок, цвета выбираются стрелками и пробелом"
+    )]
+    tui: bool,
+
+    #[arg(
+        short,
+        long,
+        help = "В интерактивном режиме после каждой догадки показывать, сколько бит неопределённости осталось.\n\
+        Кроме того, печатать на stderr отладочный лог фильтра (то же, что RUST_LOG=wordle_solver=debug)\n\
+        и время расчёта подсказок"
+    )]
+    verbose: bool,
+
+    #[arg(
+        long,
+        value_name = "N",
+        default_value_t = MAX_GUESSES,
+        help = "Сколько попыток в игре: интерактивный режим после каждой догадки напоминает, сколько осталось,\n\
+        и предупреждает о последней; --play заканчивает партию, когда они кончились, а сетка эмодзи\n\
+        показывает их число в заголовке"
+    )]
+    max_guesses: usize,
+
+    #[arg(
+        long,
+        conflicts_with_all = ["interactive", "answers", "frequency", "suggest", "hint", "freq", "simulate", "play", "auto", "boards", "absurdle", "serve", "tui", "strict_guess"],
+        help = "(фича mmap) Отобразить словарь в память и фильтровать его без копирования слов\n\
+        (для очень больших словарей). Только пакетная фильтрация: без подсказок, игры и интерактивного режима"
+    )]
+    mmap: bool,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Напечатать скрипт автодополнения для оболочки
+    #[command(hide = true)]
+    Completions { shell: Shell },
+}
+
+const INTERACTIVE_HELP: &str = "Команды:\n\
+    show, s — показать подходящие слова (по 50 на страницу); show more, show <номер>, show all — дальше\n\
+    new, n  — сбросить фильтр к исходному словарю\n\
+    suggest — показать лучшие следующие догадки\n\
+    pick <номер> — сыграть догадку с этим номером из последнего suggest (останется ввести шаблон)\n\
+    hint  — показать одну лучшую догадку\n\
+    best  — одна догадка по смешанной оценке: энтропия и шанс оказаться ответом (вес — --alpha)\n\
+    undo  — отменить последнюю догадку\n\
+    redo  — вернуть отменённую догадку (пока не введена новая)\n\
+    known — показать известные буквы и позиции\n\
+    history — показать введённые догадки и их шаблоны по порядку\n\
+    positions — где ещё может стоять каждая обязательная буква\n\
+    freq  — показать частоты букв среди подходящих слов\n\
+    heatmap — три самых вероятных буквы на каждой позиции, в процентах\n\
+    stats — сводка: сколько слов осталось, бит неопределённости, частые буквы по позициям\n\
+    random — случайное слово из подходящих (воспроизводимо с --seed)\n\
+    byletter [позиция] — сколько подходящих слов с каждой буквой на позиции (по умолчанию первой)\n\
+    paste — вставить несколько строк «догадка шаблон» разом (например, переписанную сетку эмодзи)\n\
+    probe [all] — слова с наибольшим охватом разных букв (среди подходящих или во всём словаре)\n\
+    split <слово> — показать, как догадка разбивает подходящие слова по шаблонам\n\
+    expected <слово> [слово…] — сколько подходящих слов в среднем останется после догадки\n\
+    try <догадка> <шаблон> — сколько слов осталось бы после догадки, не применяя её\n\
+    difficulty <слово> — за сколько догадок стратегия решает слово с нуля (лёгкое, среднее или трудное)\n\
+    explain <слово> — объяснить, почему слово подходит или не подходит под подсказки\n\
+    check <слово> — да или нет: подходит ли слово (даже не из словаря) под все догадки\n\
+    regex <выражение> — оставить слова, целиком подходящие под регулярное выражение\n\
+    anagram <буквы> — подходящие слова, составленные ровно из этих букв в любом порядке\n\
+    starts <буквы>, ends <буквы>, contains <буквы> — оставить слова с таким началом, концом или частью\n\
+    green <позиция> <буква> — поставить зелёную букву на позицию (с 1)\n\
+    yellow <позиция> <буква> — буква есть в слове, но не на этой позиции\n\
+    exclude <буквы> — исключить буквы, которых точно нет в слове\n\
+    include <буквы> — потребовать буквы, которые точно есть в слове\n\
+    save <путь> — записать подходящие слова в файл, по одному на строку\n\
+    report <путь> — записать отчёт о партии в Markdown: ходы, шаблоны, остаток слов и подсказки\n\
+    remove <слово> — убрать слово из подходящих (словарь не меняется)\n\
+    add <слово> — вернуть слово из словаря в подходящие\n\
+    session save <путь> — сохранить сессию (догадки и список слов) в JSON\n\
+    session load <путь> — продолжить сохранённую сессию\n\
+    guess <слово> [шаблон] — ввести слово как догадку, даже если это имя команды (guess known)\n\
+    help, ? — показать этот список\n\
+    exit, q — выйти из программы (или Ctrl-D)\n";
+
+const SUGGEST_TOP: usize = 10;
+
+/// Сколько подходящих слов по умолчанию можно перебирать для подсказок; больше — быстрая замена по охвату букв.
+/// Выше размера встроенного словаря, чтобы первая подсказка по нему считалась полностью.
+const MAX_CANDIDATES_FOR_SUGGEST: usize = 5000;
+
+/// Сколько лучших догадок хранить в кэше первых догадок (больше, если задан `--top` больше).
+const OPENER_TOP: usize = 10;
+
+/// Файл кэша первых догадок в домашнем каталоге.
+const OPENER_CACHE_FILE: &str = ".wordle_solver_opener.json";
+
+/// Сколько похожих слов предлагать вместо неизвестной догадки при `--strict-guess`.
+const CLOSEST_TOP: usize = 5;
+
+/// Код выхода при `--fail-on-empty`, если подходящих слов не осталось (1 — ошибка в аргументах или вводе).
+const EXIT_EMPTY: i32 = 2;
+
+/// Код выхода, если файл словаря или ответов не найден.
+const EXIT_NO_DICTIONARY: i32 = 3;
+
+/// Этапы сводки `--profile`; время подсчёта подсказок записывает `print_elapsed`.
+const LOAD_STAGE: &str = "загрузка словаря";
+const FILTER_STAGE: &str = "применение догадок";
+
+/// Печатает лучшие догадки и возвращает их слова, чтобы в интерактивном режиме одну можно было выбрать через `pick`.
+fn print_suggestions(solver: &Solver, args: &Args) -> Vec<String> {
+    let start = Instant::now();
+    let top = args.top.min(solver.dictionary().len());
+    let suggestions = match cached_opener(solver, args) {
+        Some(opener) if opener.top.len() >= top => opener.top[..top].to_vec(),
+        _ if too_many_candidates(solver, args) => return print_coverage_suggestions(solver, args),
+        _ => solver.suggestions(args.strategy, args.top),
+    };
+    print_elapsed(start, args);
+    if args.format == OutputFormat::Plain {
+        suggestions
+            .iter()
+            .for_each(|suggestion| println!("{}", suggestion.word));
+    } else {
+        println!("Лучшие догадки:\n{}", format_suggestions(&suggestions));
+    }
+    suggestions.into_iter().map(|suggestion| suggestion.word).collect()
+}
+
+/// Подсказки для `--format json` по тем же правилам, что у `print_suggestions`: из кэша первых догадок, по охвату
+/// букв при слишком длинном списке или полным подсчётом.
+fn json_suggestions(solver: &Solver, args: &Args) -> SuggestionList {
+    let top = args.top.min(solver.dictionary().len());
+    match cached_opener(solver, args) {
+        Some(opener) if opener.top.len() >= top => SuggestionList::Scored(opener.top[..top].to_vec()),
+        _ if too_many_candidates(solver, args) => {
+            note_coverage_fallback(solver, args);
+            SuggestionList::Coverage(coverage_suggestions(solver.filtered(), args.top))
+        }
+        _ => SuggestionList::Scored(solver.suggestions(args.strategy, args.top)),
+    }
+}
+
+/// Лучшая догадка для `--format json` по тем же правилам, что у `print_hint`.
+fn json_hint(solver: &Solver, args: &Args) -> Option<String> {
+    if let Some(Opener { best, .. }) = cached_opener(solver, args) {
+        return Some(best.word);
+    }
+    if too_many_candidates(solver, args) {
+        note_coverage_fallback(solver, args);
+        return coverage_suggestions(solver.filtered(), 1)
+            .pop()
+            .map(|suggestion| suggestion.word);
+    }
+    solver.best_guess_by(args.strategy)
+}
+
+/// Больше ли подходящих слов, чем `--max-candidates-for-suggest`, чтобы считать подсказки полностью.
+fn too_many_candidates(solver: &Solver, args: &Args) -> bool {
+    solver.too_many_candidates(args.max_candidates_for_suggest)
+}
+
+/// Примечание о том, что подсказки выбраны по охвату букв; в stderr, чтобы не мешать `--format plain`.
+fn note_coverage_fallback(solver: &Solver, args: &Args) {
+    eprintln!(
+        "Примечание: подходящих слов {} — больше, чем --max-candidates-for-suggest {}, поэтому догадки выбраны \
+        по охвату букв без подсчёта энтропии.",
+        solver.filtered().len(),
+        args.max_candidates_for_suggest
+    );
+}
+
+/// Замена `suggest` при слишком длинном списке: подходящие слова по убыванию `coverage_score`.
+fn print_coverage_suggestions(solver: &Solver, args: &Args) -> Vec<String> {
+    let start = Instant::now();
+    let ranked: Vec<(&String, u32)> = rank_probes(solver.filtered()).into_iter().take(args.top).collect();
+    print_elapsed(start, args);
+    note_coverage_fallback(solver, args);
+    if args.format == OutputFormat::Plain {
+        ranked.iter().for_each(|(word, _)| println!("{}", word));
+    } else {
+        println!("Лучшие догадки по охвату букв (гласная — 2 очка, согласная — 1):");
+        for (i, (word, score)) in ranked.iter().enumerate() {
+            println!("{:>3}  {} — {}", i + 1, word, score);
+        }
+    }
+    ranked.into_iter().map(|(word, _)| word.clone()).collect()
+}
+
+fn print_hint(solver: &Solver, args: &Args) {
+    let plain = args.format == OutputFormat::Plain;
+    let start = Instant::now();
+    if let Some(Opener { best, .. }) = cached_opener(solver, args) {
+        print_elapsed(start, args);
+        match args.strategy {
+            _ if plain => println!("{}", best.word),
+            Strategy::Entropy => println!("Подсказка: {} — {:.3} бит", best.word, best.entropy),
+            Strategy::Minimax => println!("Подсказка: {} — наибольшая группа {}", best.word, best.worst_case),
+        }
+        return;
+    }
+    if too_many_candidates(solver, args) {
+        let best = rank_probes(solver.filtered()).into_iter().next();
+        print_elapsed(start, args);
+        note_coverage_fallback(solver, args);
+        match best {
+            Some((word, _)) if plain => println!("{}", word),
+            Some((word, score)) => println!("Подсказка: {} — охват букв {}", word, score),
+            None => println!("Подсказать нечего: словарь пуст."),
+        }
+        return;
+    }
+    match args.strategy {
+        Strategy::Entropy => {
+            let best = solver.best_guess();
+            print_elapsed(start, args);
+            match best {
+                Some((word, _)) if plain => println!("{}", word),
+                Some((word, bits)) => println!("Подсказка: {} — {:.3} бит", word, bits),
+                None => println!("Подсказать нечего: словарь пуст."),
+            }
+        }
+        Strategy::Minimax => {
+            let best = solver.best_guess_minimax();
+            print_elapsed(start, args);
+            match best {
+                Some((word, _)) if plain => println!("{}", word),
+                Some((word, worst)) => println!("Подсказка: {} — наибольшая группа {}", word, worst),
+                None => println!("Подсказать нечего: словарь пуст."),
+            }
+        }
+    }
+}
+
+/// С `--verbose` печатает на stderr, сколько времени заняло вычисление подсказок с момента `start`.
+/// Это время идёт и в сводку `--profile`.
+fn print_elapsed(start: Instant, args: &Args) {
+    profile::record("подсказки", start.elapsed());
+    if args.verbose {
+        eprintln!("Подсказки посчитаны за {:.2?}", start.elapsed());
+    }
+}
+
+/// Путь к кэшу первых догадок: `--opener-cache` или файл в домашнем каталоге.
+fn opener_cache_path(args: &Args) -> Option<PathBuf> {
+    match &args.opener_cache {
+        Some(path) => Some(PathBuf::from(path)),
+        None => std::env::var_os("HOME")
+            .or_else(|| std::env::var_os("USERPROFILE"))
+            .map(|home| Path::new(&home).join(OPENER_CACHE_FILE)),
+    }
+}
+
+/// Первая догадка из кэша, если к решателю ещё ничего не применено, а словарь не изменился с `--compute-opener`.
+fn cached_opener(solver: &Solver, args: &Args) -> Option<Opener> {
+    let path = opener_cache_path(args)?;
+    match OpenerCache::load(&path) {
+        Ok(cache) => cache.get(solver, args.strategy).cloned(),
+        Err(e) => {
+            eprintln!(
+                "Предупреждение: кэш первых догадок {} не прочитан: {}",
+                path.display(),
+                e
+            );
+            None
+        }
+    }
+}
+
+/// `--compute-opener`: считает первую догадку по всему словарю и записывает её в кэш.
+fn compute_opener(solver: &Solver, args: &Args) {
+    let Some(path) = opener_cache_path(args) else {
+        eprintln!("Ошибка: не найден домашний каталог; укажите файл кэша через --opener-cache");
+        process::exit(1);
+    };
+    let mut cache = OpenerCache::load(&path).unwrap_or_else(|e| {
+        eprintln!(
+            "Предупреждение: кэш {} не прочитан ({}) и будет перезаписан",
+            path.display(),
+            e
+        );
+        OpenerCache::default()
+    });
+    let Some(opener) = Opener::compute(solver, args.strategy, args.top.max(OPENER_TOP)) else {
+        eprintln!("Ошибка: словарь пуст");
+        process::exit(1);
+    };
+    println!("Первая догадка: {}", opener.best.word);
+    println!("Лучшие догадки:\n{}", format_suggestions(&opener.top));
+    cache.insert(solver, args.strategy, opener);
+    if let Err(e) = cache.save(&path) {
+        eprintln!("Ошибка: не удалось сохранить {}: {}", path.display(), e);
+        process::exit(1);
+    }
+    eprintln!("Кэш записан в {}.", path.display());
+}
+
+fn print_simulation(stats: &SimulationStats) {
+    println!("Сыграно партий: {}", stats.games);
+    println!("Среднее число догадок в решённых партиях: {:.3}", stats.mean());
+    println!("Максимум догадок: {}", stats.max_guesses);
+    for (i, count) in stats.histogram[..MAX_GUESSES].iter().enumerate() {
+        println!("{}: {}", i + 1, count);
+    }
+    println!("не решено: {}", stats.failed());
+    if stats.unsolved > 0 {
+        println!("из них ответ не угадан вовсе: {}", stats.unsolved);
+    }
+}
+
+/// Подсказка интерактивного режима, когда осталось единственное слово.
+const SOLVED_HINT: &str = "Ответ найден: введите exit, чтобы выйти, или undo, чтобы отменить последнюю догадку.";
+
+const DEFAULT_DICTIONARY: &str = "wordle-La.txt";
+const DEFAULT_EMBEDDED: &str = "wordle-La";
+
+fn load_words(dictionary: Option<&str>, options: DictionaryOptions) -> io::Result<Vec<String>> {
+    let (path, result) = match dictionary {
+        Some(path) => match path.strip_prefix(EMBEDDED_PREFIX) {
+            Some(name) => {
+                return embedded_dictionary(name).ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::NotFound,
+                        format!("встроенный словарь '{}' не найден (см. --list-embedded)", name),
+                    )
+                });
+            }
+            None => (path, load_dictionary_with(path, options)),
+        },
+        None => match load_dictionary_with(DEFAULT_DICTIONARY, options) {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                eprintln!(
+                    "Примечание: файл {} не найден, используется встроенный словарь {}",
+                    DEFAULT_DICTIONARY, DEFAULT_EMBEDDED
+                );
+                return Ok(embedded_dictionary(DEFAULT_EMBEDDED).expect("встроенный словарь по умолчанию"));
+            }
+            result => (DEFAULT_DICTIONARY, result),
+        },
+    };
+    let (words, rejected) = result?;
+    if rejected > 0 {
+        eprintln!(
+            "Предупреждение: {}: пропущено {} строк не только из букв (см. --allow-nonalpha)",
+            path, rejected
+        );
+    }
+    Ok(words)
+}
+
+/// Как сравнивать буквы: с `--case-sensitive` — точно, иначе без учёта регистра.
+fn letter_case(args: &Args) -> LetterCase {
+    if args.case_sensitive {
+        LetterCase::Sensitive
+    } else {
+        LetterCase::Insensitive
+    }
+}
+
+/// Проверенные пары (догадка, шаблон) пакетного режима: из `--guess`/`--pattern`, из stdin (`--stdin`)
+/// или из файла `--replay`.
+fn batch_history(args: &Args, length: usize) -> Vec<(String, String)> {
+    if args.guess.len() != args.pattern.len() {
+        eprintln!(
+            "Ошибка: число догадок ({}) не совпадает с числом шаблонов ({})",
+            args.guess.len(),
+            args.pattern.len()
+        );
+        process::exit(1);
+    }
+    let mut history = Vec::new();
+    for (guess, pattern) in args.guess.iter().zip(&args.pattern) {
+        let pattern = normalize_pattern_with(pattern, &args.pattern_map);
+        let valid =
+            validate_guess(guess, length).and_then(|_| validate_pattern_with(guess, &pattern, letter_case(args)));
+        if let Err(e) = valid {
+            eprintln!("Ошибка: {}: {}", guess, e);
+            process::exit(1);
+        }
+        history.push((guess.clone(), pattern));
+    }
+    if args.stdin {
+        history = parse_history_with(io::stdin().lock(), length, &args.pattern_map).unwrap_or_else(|e| {
+            eprintln!("Ошибка: {}", e);
+            process::exit(1);
+        });
+    }
+    if let Some(path) = &args.replay {
+        history = File::open(path)
+            .map_err(|e| e.to_string())
+            .and_then(|file| parse_history_with(io::BufReader::new(file), length, &args.pattern_map))
+            .unwrap_or_else(|e| {
+                eprintln!("Ошибка: --replay {}: {}", path, e);
+                process::exit(1);
+            });
+    }
+    history
+}
+
+/// `--replay`: применяет пары по одной и после каждой передаёт в `report` строку с числом оставшихся слов.
+fn replay_history(solver: &mut Solver, history: &[(String, String)], args: &Args, report: impl Fn(String)) {
+    for (i, step) in history.iter().enumerate() {
+        apply_history(solver, std::slice::from_ref(step), args);
+        report(format!(
+            "Шаг {}: {} {} — осталось {} слов",
+            i + 1,
+            step.0,
+            step.1,
+            solver.filtered().len()
+        ));
+    }
+}
+
+/// Применяет пары из `--guess`/`--pattern` или `--stdin`; с `--strict-guess` и `--hard` неподходящая догадка
+/// завершает программу. С `--show-removed` убранные каждой парой слова печатаются на stderr.
+fn apply_history(solver: &mut Solver, history: &[(String, String)], args: &Args) {
+    for (guess, pattern) in history {
+        if args.strict_guess
+            && let Err(e) = validate_known(guess, solver.dictionary(), CLOSEST_TOP)
+        {
+            eprintln!("Ошибка: {}", e);
+            process::exit(1);
+        }
+        if args.hard
+            && let Some(reason) = violates_hard_mode(guess, solver.history())
+        {
+            eprintln!("Сложный режим: {}: {}", guess, reason);
+            process::exit(1);
+        }
+        match args.show_removed {
+            Some(limit) => {
+                let before = solver.filtered().to_vec();
+                profile::time(FILTER_STAGE, || solver.apply(guess, pattern));
+                eprintln!(
+                    "{} {}: {}",
+                    guess,
+                    pattern,
+                    format_removed(&before, solver.filtered(), guess, pattern, limit, solver.case())
+                );
+            }
+            None => {
+                profile::time(FILTER_STAGE, || solver.apply(guess, pattern));
+            }
+        }
+    }
+}
+
+/// Позиция (с 0) и буква из `--green` или `--yellow`.
+type Placement = (usize, char);
+
+/// Проверенные позиции `--green` и `--yellow`; заодно проверяет буквы `--exclude`, `--include`, `--starts`,
+/// `--ends` и `--contains`.
+fn batch_placements(args: &Args, length: usize) -> (Vec<Placement>, Vec<Placement>) {
+    for letters in [&args.exclude, &args.include, &args.starts, &args.ends, &args.contains]
+        .into_iter()
+        .flatten()
+    {
+        if let Err(e) = validate_letters(letters) {
+            eprintln!("Ошибка: {}: {}", letters, e);
+            process::exit(1);
+        }
+    }
+    let parse = |placement: &String| {
+        parse_placement(placement, length).unwrap_or_else(|e| {
+            eprintln!("Ошибка: {}", e);
+            process::exit(1);
+        })
+    };
+    (
+        args.green.iter().map(parse).collect(),
+        args.yellow.iter().map(parse).collect(),
+    )
+}
+
+/// Печатает число подходящих слов (`--count`) или их список, если в пакетном режиме задан хоть один фильтр.
+/// В текстовом формате после списка из одного или двух слов печатается `format_solution`.
+fn print_filtered(words: &[impl AsRef<str>], args: &Args, has_history: bool) {
+    let filtered = has_history
+        || args.stdin
+        || args.replay.is_some()
+        || !args.green.is_empty()
+        || !args.yellow.is_empty()
+        || args.exclude.is_some()
+        || args.include.is_some()
+        || !batch_fragments(args).is_empty();
+    if args.count {
+        println!("{}", words.len());
+    } else if filtered && args.format != OutputFormat::Json {
+        if args.format == OutputFormat::Text {
+            println!("Подходит {} слов:", words.len());
+        }
+        for word in words {
+            println!("{}", word.as_ref());
+        }
+        if args.format == OutputFormat::Text
+            && let Some(solution) = format_solution(words)
+        {
+            println!("{}", solution);
+        }
+    }
+}
+
+/// Фильтры `--starts`, `--ends` и `--contains` с буквами в том виде, в каком они сравниваются (`letter_case`).
+fn batch_fragments(args: &Args) -> Vec<(Fragment, String)> {
+    let case = letter_case(args);
+    [
+        (Fragment::Prefix, &args.starts),
+        (Fragment::Suffix, &args.ends),
+        (Fragment::Substring, &args.contains),
+    ]
+    .into_iter()
+    .filter_map(|(fragment, letters)| letters.as_ref().map(|letters| (fragment, case.fold_word(letters))))
+    .collect()
+}
+
+/// Команды `starts`, `ends` и `contains`: оставляет слова с этими буквами и печатает, сколько осталось.
+fn retain_fragment(solver: &mut Solver, fragment: Fragment, letters: &str) {
+    if let Err(e) = validate_letters(letters) {
+        println!("Ошибка: {}", e);
+        return;
+    }
+    let letters = solver.case().fold_word(letters);
+    println!(
+        "Подходит {} слов.",
+        solver.retain(|word| fragment.matches(word, &letters))
+    );
+}
+
+/// Записывает подходящие слова в файл `--output`.
+fn write_words(path: &str, words: &[impl AsRef<str>]) {
+    if let Err(e) = save_words(path, words) {
+        eprintln!("Ошибка: не удалось сохранить {}: {}", path, e);
+        process::exit(1);
+    }
+    eprintln!("Сохранено {} слов в {}.", words.len(), path);
+}
+
+/// Пакетная фильтрация словаря, отображённого в память (`--mmap`): слова проверяются прямо в отображённом
+/// файле, без `Solver` и без копий. Порядок — как в файле.
+#[cfg(feature = "mmap")]
+fn filter_mapped(args: &Args, options: DictionaryOptions) {
+    if args.dictionary.len() > 1 {
+        eprintln!("Ошибка: --mmap работает только с одним словарём");
+        process::exit(1);
+    }
+    let path = args.dictionary.first().map_or(DEFAULT_DICTIONARY, String::as_str);
+    if path.starts_with(EMBEDDED_PREFIX) {
+        eprintln!("Ошибка: --mmap работает только со словарём из файла");
+        process::exit(1);
+    }
+    let words = MappedWords::open(path, options).unwrap_or_else(|e| exit_on_dictionary_error(path, "--dictionary", e));
+    if words.rejected() > 0 {
+        eprintln!(
+            "Предупреждение: {}: пропущено {} строк не только из букв (см. --allow-nonalpha)",
+            path,
+            words.rejected()
+        );
+    }
+    let length = args.length.unwrap_or_else(|| {
+        let length = words.modal_length().unwrap_or(5);
+        if words.iter().any(|word| word.chars().count() != length) {
+            warn_mixed_lengths(length);
+        }
+        length
+    });
+
+    let history = batch_history(args, length);
+    let (greens, yellows) = batch_placements(args, length);
+    for (i, (guess, _)) in history.iter().enumerate() {
+        if args.hard
+            && let Some(reason) = violates_hard_mode(guess, &history[..i])
+        {
+            eprintln!("Сложный режим: {}: {}", guess, reason);
+            process::exit(1);
+        }
+    }
+    let mut constraints = Constraints::from_history_with(&history, letter_case(args));
+    for (position, letter) in greens {
+        constraints.set_green(position, letter, length);
+    }
+    for (position, letter) in yellows {
+        constraints.add_yellow(position, letter, length);
+    }
+    args.exclude
+        .iter()
+        .flat_map(|letters| letters.chars())
+        .for_each(|letter| constraints.exclude(letter));
+    args.include
+        .iter()
+        .flat_map(|letters| letters.chars())
+        .for_each(|letter| constraints.include(letter));
+
+    let fragments = batch_fragments(args);
+    let matching: Vec<&str> = words
+        .iter()
+        .filter(|word| word.chars().count() == length && constraints.matches(word))
+        .filter(|word| {
+            fragments
+                .iter()
+                .all(|(fragment, letters)| fragment.matches(word, letters))
+        })
+        .collect();
+    print_filtered(&matching, args, !history.is_empty());
+    if let Some(path) = &args.output {
+        write_words(path, &matching);
+    }
+    if args.format == OutputFormat::Json {
+        let owned: Vec<String> = matching.iter().map(|word| word.to_string()).collect();
+        match serde_json::to_string(&FilterResult::new(&owned)) {
+            Ok(json) => println!("{}", json),
+            Err(e) => {
+                eprintln!("Ошибка: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+    if args.fail_on_empty && matching.is_empty() {
+        process::exit(EXIT_EMPTY);
+    }
+}
 
-    #[arg(short, long, help = "Включить интерактивный режим")]
-    interactive: bool,
+fn warn_mixed_lengths(length: usize) {
+    eprintln!(
+        "Предупреждение: в словаре слова разной длины, используются только слова из {} букв (см. --length)",
+        length
+    );
 }
 
-fn load_dictionary<P: AsRef<Path>>(filename: P) -> io::Result<Vec<String>> {
-    let file = File::open(filename)?;
-    let reader = io::BufReader::new(file);
-    let words = reader.lines().filter_map(Result::ok).collect();
-    Ok(words)
+/// Загружает словарь, как `load_words`, а при ошибке объясняет, что делать, и завершает программу.
+/// `flag` — аргумент, которым задан путь (`--dictionary` или `--answers`).
+fn load_words_or_exit(dictionary: Option<&str>, flag: &str, options: DictionaryOptions) -> Vec<String> {
+    load_words(dictionary, options)
+        .unwrap_or_else(|error| exit_on_dictionary_error(dictionary.unwrap_or(DEFAULT_DICTIONARY), flag, error))
 }
 
-fn check_greens(word_chars: &[char], guess_chars: &[char], pattern_chars: &[char], used_in_word: &mut [bool]) -> bool {
-    for i in 0..word_chars.len() {
-        if pattern_chars[i].eq_ignore_ascii_case(&'g') {
-            if word_chars[i] != guess_chars[i] {
-                return false;
-            }
-            used_in_word[i] = true;
+/// Загружает все словари из `--dictionary` и объединяет их (`merge_dictionaries`); без `--dictionary` —
+/// словарь по умолчанию. Если словарей несколько, сообщает, сколько в них вместе разных слов.
+fn load_dictionaries_or_exit(paths: &[String], options: DictionaryOptions) -> Vec<String> {
+    if paths.len() < 2 {
+        return load_words_or_exit(paths.first().map(String::as_str), "--dictionary", options);
+    }
+    let words = merge_dictionaries(
+        paths
+            .iter()
+            .map(|path| load_words_or_exit(Some(path), "--dictionary", options)),
+    );
+    eprintln!(
+        "Объединено словарей: {}, всего {} разных слов.",
+        paths.len(),
+        words.len()
+    );
+    words
+}
+
+/// Словари из `--dictionary` одной строкой, как их запоминает сессия. `None` — словарь по умолчанию.
+fn dictionary_label(args: &Args) -> Option<String> {
+    (!args.dictionary.is_empty()).then(|| args.dictionary.join(","))
+}
+
+/// Объясняет ошибку чтения словаря `path` и завершает программу.
+fn exit_on_dictionary_error(path: &str, flag: &str, error: io::Error) -> ! {
+    match error.kind() {
+        io::ErrorKind::NotFound if path.starts_with(EMBEDDED_PREFIX) => {
+            eprintln!("Ошибка: {}", error);
+            process::exit(EXIT_NO_DICTIONARY);
+        }
+        io::ErrorKind::NotFound => {
+            eprintln!(
+                "Ошибка: файл словаря '{}' не найден. Укажите другой через {} или выберите встроенный (--list-embedded).",
+                path, flag
+            );
+            process::exit(EXIT_NO_DICTIONARY);
+        }
+        io::ErrorKind::PermissionDenied => {
+            eprintln!("Ошибка: нет прав на чтение файла словаря '{}'", path);
+            process::exit(1);
+        }
+        _ => {
+            eprintln!("Ошибка: не удалось прочитать словарь '{}': {}", path, error);
+            process::exit(1);
         }
     }
-    true
 }
 
-fn check_yellows(word_chars: &[char], guess_chars: &[char], pattern_chars: &[char], used_in_word: &mut [bool]) -> bool {
-    for i in 0..word_chars.len() {
-        if pattern_chars[i].eq_ignore_ascii_case(&'y') {
-            if word_chars[i] == guess_chars[i] {
-                return false;
+/// Объясняет ошибку чтения файла частот `path` (`--frequency`) и завершает программу.
+fn exit_on_frequency_error(path: &str, error: io::Error) -> ! {
+    let reason = match error.kind() {
+        io::ErrorKind::NotFound => "файл не найден".to_string(),
+        io::ErrorKind::PermissionDenied => "нет прав на чтение".to_string(),
+        _ => error.to_string(),
+    };
+    eprintln!(
+        "Ошибка: не удалось прочитать файл частот '{}' (--frequency): {}",
+        path, reason
+    );
+    process::exit(1);
+}
+
+/// Ввод строк в интерактивном режиме. С фичей `readline` в терминале строку можно редактировать,
+/// а стрелкой вверх — вернуть прошлый ввод; история хранится в `~/.wordle_solver_history`.
+/// Ctrl-C отменяет текущую строку, Ctrl-D завершает ввод.
+struct Prompter {
+    #[cfg(feature = "readline")]
+    editor: Option<rustyline::DefaultEditor>,
+}
+
+#[cfg(feature = "readline")]
+const HISTORY_FILE: &str = ".wordle_solver_history";
+
+impl Prompter {
+    fn new() -> Self {
+        #[cfg(feature = "readline")]
+        {
+            let mut editor = if io::stdin().is_terminal() {
+                rustyline::DefaultEditor::new().ok()
+            } else {
+                None
+            };
+            if let (Some(editor), Some(path)) = (&mut editor, Prompter::history_path()) {
+                let _ = editor.load_history(&path);
             }
-            let mut found = false;
-            for j in 0..word_chars.len() {
-                if !used_in_word[j] && word_chars[j] == guess_chars[i] && j != i {
-                    used_in_word[j] = true;
-                    found = true;
-                    break;
+            Prompter { editor }
+        }
+        #[cfg(not(feature = "readline"))]
+        Prompter {}
+    }
+
+    #[cfg(feature = "readline")]
+    fn history_path() -> Option<std::path::PathBuf> {
+        std::env::var_os("HOME")
+            .or_else(|| std::env::var_os("USERPROFILE"))
+            .map(|home| std::path::Path::new(&home).join(HISTORY_FILE))
+    }
+
+    /// Читает строку без пробелов по краям; при конце ввода — пустую строку.
+    fn prompt(&mut self, text: &str) -> io::Result<String> {
+        #[cfg(feature = "readline")]
+        if let Some(editor) = &mut self.editor {
+            use rustyline::error::ReadlineError;
+            loop {
+                match editor.readline(text) {
+                    Ok(line) => {
+                        let line = line.trim().to_string();
+                        if !line.is_empty() {
+                            let _ = editor.add_history_entry(line.as_str());
+                        }
+                        return Ok(line);
+                    }
+                    Err(ReadlineError::Interrupted) => continue,
+                    Err(ReadlineError::Eof) => return Ok(String::new()),
+                    Err(e) => return Err(io::Error::other(e)),
                 }
             }
-            if !found {
-                return false;
-            }
         }
+
+        print!("{}", text);
+        io::stdout().flush()?;
+        let mut input = String::new();
+        io::stdin().read_line(&mut input)?;
+        Ok(input.trim().to_string())
+    }
+}
+
+impl Drop for Prompter {
+    fn drop(&mut self) {
+        #[cfg(feature = "readline")]
+        if let (Some(editor), Some(path)) = (&mut self.editor, Prompter::history_path()) {
+            let _ = editor.save_history(&path);
+        }
+    }
+}
+
+/// Спрашивает шаблон для догадки, пока он не окажется верным. Пустой ввод — `None`.
+fn prompt_pattern(prompter: &mut Prompter, text: &str, guess: &str, map: &PatternMap) -> io::Result<Option<String>> {
+    loop {
+        let pattern = normalize_pattern_with(&prompter.prompt(text)?, map);
+        if pattern.is_empty() {
+            return Ok(None);
+        }
+        match validate_pattern(guess, &pattern) {
+            Ok(()) => return Ok(Some(pattern)),
+            Err(e) => println!("Ошибка: {}", e),
+        }
+    }
+}
+
+/// Номер сегодняшнего дня (UTC) от 1970-01-01.
+fn today() -> u64 {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+    now.as_secs() / 86_400
+}
+
+/// Зерно для `random` без `--seed`: случайное значение от системы.
+fn random_seed() -> u64 {
+    RandomState::new().hash_one(process::id())
+}
+
+/// Игра против загаданного слова `answer`: догадки читаются с клавиатуры, шаблон печатается после каждой.
+fn play_game(solver: &Solver, answer: &str, args: &Args) -> io::Result<()> {
+    let length = answer.chars().count();
+    let color = args.color.enabled();
+    let mut game = Game::with_case(answer, solver.case());
+    game.set_max_guesses(args.max_guesses);
+    println!(
+        "Загадано слово из {} букв. У вас {} попыток; пустой ввод — сдаться.",
+        length,
+        game.max_guesses()
+    );
+    if args.cheat {
+        println!("Загаданное слово: {}", answer);
+    }
+
+    let mut prompter = Prompter::new();
+    while !game.is_over() {
+        let text = format!("Попытка {}/{}: ", game.history().len() + 1, game.max_guesses());
+        let guess = solver.case().fold_word(&prompter.prompt(&text)?);
+        if guess.is_empty() {
+            break;
+        }
+        if let Err(e) = validate_guess(&guess, length) {
+            println!("Ошибка: {}", e);
+            continue;
+        }
+        if !solver.dictionary().contains(&guess) {
+            println!("Слова '{}' нет в словаре.", guess);
+            continue;
+        }
+        if args.hard
+            && let Some(reason) = violates_hard_mode(&guess, game.history())
+        {
+            println!("Сложный режим: {}", reason);
+            continue;
+        }
+        let pattern = game.guess(&guess);
+        if color {
+            println!("{}", render_colored(&guess, &pattern));
+        } else {
+            println!("{}", pattern);
+        }
+    }
+
+    let guesses: Vec<String> = game.history().iter().map(|(guess, _)| guess.clone()).collect();
+    if game.is_won() {
+        println!("Угадано за {}/{}!", guesses.len(), game.max_guesses());
+        println!("{}", emoji_grid(answer, &guesses, solver.case(), game.max_guesses()));
+    } else {
+        println!("Не угадано. Загаданное слово: {}", answer);
     }
-    true
+    Ok(())
 }
 
-fn check_blacks(word_chars: &[char], guess_chars: &[char], pattern_chars: &[char], used_in_word: &[bool]) -> bool {
-    for i in 0..word_chars.len() {
-        if pattern_chars[i].eq_ignore_ascii_case(&'b') {
-            for j in 0..word_chars.len() {
-                if !used_in_word[j] && word_chars[j] == guess_chars[i] {
-                    return false;
+/// Несколько полей сразу (`--boards`): догадка общая, а шаблон вводится отдельно для каждого нерешённого поля.
+fn solve_boards(mut boards: Boards, length: usize, map: &PatternMap) -> io::Result<()> {
+    println!(
+        "Полей: {}. Введите догадку, затем шаблон для каждого нерешённого поля (пустой шаблон отменяет догадку).\n\
+        Команды: show — подходящие слова по полям, new — начать заново, exit — выйти.\n",
+        boards.len()
+    );
+    let mut prompter = Prompter::new();
+    while !boards.unsolved().is_empty() {
+        let input = prompter.prompt("Введите guess (или show, new, exit): ")?;
+        let guess = match parse_command_with(&input, map) {
+            InteractiveCommand::Exit => return Ok(()),
+            InteractiveCommand::Show(_) => {
+                for board in 0..boards.len() {
+                    println!("Поле {}:", board + 1);
+                    for word in boards.board(board).ranked() {
+                        println!("  {}", word);
+                    }
                 }
+                continue;
+            }
+            InteractiveCommand::New => {
+                boards.reset();
+                println!("Все поля сброшены.");
+                continue;
+            }
+            InteractiveCommand::Guess(guess) => guess,
+            _ => {
+                println!("С несколькими полями доступны только show, new и exit.");
+                continue;
+            }
+        };
+        if let Err(e) = validate_guess(guess, length) {
+            println!("Ошибка: {}", e);
+            continue;
+        }
+
+        let mut patterns = Vec::new();
+        for board in boards.unsolved() {
+            match prompt_pattern(&mut prompter, &format!("Шаблон для поля {}: ", board + 1), guess, map)? {
+                Some(pattern) => patterns.push((board, pattern)),
+                None => break,
             }
         }
+        if patterns.len() < boards.unsolved().len() {
+            println!("Догадка отменена.");
+            continue;
+        }
+        for (board, pattern) in patterns {
+            boards.apply(board, guess, &pattern);
+        }
+        println!("{}", format_boards(&boards));
+    }
+    println!("Все поля решены!");
+    Ok(())
+}
+
+/// Absurdle (`--absurdle`): программа отвечает на каждую догадку шаблоном, оставляющим больше всего слов,
+/// пока ответ не станет единственным и не будет угадан.
+fn play_absurdle(solver: &Solver, args: &Args) -> io::Result<()> {
+    let Some(length) = solver.answers().first().map(|word| word.chars().count()) else {
+        eprintln!("Ошибка: список ответов пуст");
+        process::exit(1);
+    };
+    let color = args.color.enabled();
+    let mut game = Absurdle::new(solver.answers());
+    println!(
+        "Absurdle: слово не загадано заранее — на каждую догадку выбирается шаблон, оставляющий больше всего слов.\n\
+        Попыток сколько угодно; пустой ввод — сдаться."
+    );
+
+    let mut prompter = Prompter::new();
+    while !game.is_won() {
+        let text = format!("Попытка {}: ", game.history().len() + 1);
+        let guess = solver.case().fold_word(&prompter.prompt(&text)?);
+        if guess.is_empty() {
+            println!(
+                "Сдаётесь. Ответом могло быть любое из {} слов, например {}.",
+                game.candidates().len(),
+                game.candidates()[0]
+            );
+            return Ok(());
+        }
+        if let Err(e) = validate_guess(&guess, length) {
+            println!("Ошибка: {}", e);
+            continue;
+        }
+        if !solver.dictionary().contains(&guess) {
+            println!("Слова '{}' нет в словаре.", guess);
+            continue;
+        }
+        if args.hard
+            && let Some(reason) = violates_hard_mode(&guess, game.history())
+        {
+            println!("Сложный режим: {}", reason);
+            continue;
+        }
+        let pattern = game.guess(&guess);
+        let shown = if color {
+            render_colored(&guess, &pattern)
+        } else {
+            pattern
+        };
+        println!("{} — осталось {} слов", shown, game.candidates().len());
+    }
+
+    let guesses: Vec<String> = game.history().iter().map(|(guess, _)| guess.clone()).collect();
+    println!("Угадано за {} догадок!", guesses.len());
+    println!(
+        "{}",
+        emoji_grid(&game.candidates()[0], &guesses, solver.case(), args.max_guesses)
+    );
+    Ok(())
+}
+
+/// Создаёт CSV для `--bench-csv`; с `with_opener` первым идёт столбец первой догадки (`--first-guess`).
+fn create_bench_csv(path: &str, with_opener: bool) -> BufWriter<File> {
+    let created = File::create(path).map(BufWriter::new).and_then(|mut writer| {
+        let opener = if with_opener { "opener," } else { "" };
+        writeln!(writer, "{}answer,guesses,solved", opener)?;
+        Ok(writer)
+    });
+    created.unwrap_or_else(|e| {
+        eprintln!("Ошибка: не удалось создать {}: {}", path, e);
+        process::exit(1);
+    })
+}
+
+/// Поле CSV: в кавычках, если в нём есть запятая, кавычка или перевод строки.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Решает партию против `answer` без участия игрока (`play_on`), печатая каждую догадку, её шаблон и остаток слов.
+fn auto_solve(solver: &mut Solver, answer: &str, args: &Args) {
+    let color = args.color.enabled();
+    let opener = args.first_guess.first().map(String::as_str);
+    let guesses = play_on(solver, answer, opener, args.strategy);
+    let mut remaining = solver.filtered().len();
+    for (turn, guess) in guesses.iter().enumerate() {
+        let pattern = score_guess_with(answer, guess, solver.case());
+        remaining = solver.apply(guess, &pattern);
+        let shown = if color {
+            render_colored(guess, &pattern)
+        } else {
+            format!("{} {}", guess, pattern)
+        };
+        println!("{}. {} — осталось {} слов", turn + 1, shown, remaining);
+    }
+    if is_solved(answer, &guesses, solver.case()) {
+        println!("Решено за {} догадок.", guesses.len());
+        if args.emoji {
+            println!("{}", emoji_grid(answer, &guesses, solver.case(), args.max_guesses));
+        }
+    } else if remaining == 0 {
+        println!("Не удалось решить: подходящих слов не осталось.");
+    } else {
+        println!("Не удалось решить: догадки больше не сужают список подходящих слов.");
+    }
+}
+
+/// Разбирает `--alpha`: число от 0 до 1.
+fn parse_alpha(value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(alpha) if (0.0..=1.0).contains(&alpha) => Ok(alpha),
+        Ok(_) => Err("должно быть от 0 до 1".to_string()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Проверяет `--first-guess`: флаг нужен только самоигре, в `--auto` первая догадка одна, и каждая должна быть в
+/// списке допустимых догадок. Слова приводятся к нижнему регистру.
+fn check_first_guesses(args: &mut Args, solver: &Solver) {
+    let fail = |message: String| -> ! {
+        eprintln!("Ошибка: {}", message);
+        process::exit(1);
+    };
+    if args.first_guess.is_empty() {
+        return;
+    }
+    if !args.simulate && !args.auto {
+        fail("--first-guess работает только с --simulate или --auto".to_string());
+    }
+    if args.auto && args.first_guess.len() > 1 {
+        fail("в --auto можно задать только одну --first-guess".to_string());
+    }
+    for word in &mut args.first_guess {
+        *word = solver.case().fold_word(word);
+        if let Err(e) = validate_known(word, solver.dictionary(), CLOSEST_TOP) {
+            fail(format!("--first-guess: {}", e));
+        }
+    }
+}
+
+/// Проверки `--strict-guess` и `--hard` для строк `paste` — те же, что у обычной догадки. Каждая строка
+/// проверяется с учётом уже введённых догадок и строк перед ней; ошибка называет номер строки.
+fn check_pasted_guesses(solver: &Solver, pasted: &[(String, String)], args: &Args) -> Result<(), String> {
+    let mut history = solver.history().to_vec();
+    for (i, (guess, pattern)) in pasted.iter().enumerate() {
+        if args.strict_guess
+            && let Err(e) = validate_known(guess, solver.dictionary(), CLOSEST_TOP)
+        {
+            return Err(format!("строка {}: {}", i + 1, e));
+        }
+        if args.hard
+            && let Some(reason) = violates_hard_mode(guess, &history)
+        {
+            return Err(format!("строка {}: сложный режим: {}", i + 1, reason));
+        }
+        history.push((guess.clone(), pattern.clone()));
+    }
+    Ok(())
+}
+
+/// Слово под номером `number` (с 1) из последнего `suggest` для команды `pick`. `stale` — список подходящих
+/// слов изменился после `suggest` (undo, new, remove, regex и т. п.), и догадки посчитаны не для него.
+fn pick_suggestion<'a>(suggestions: &'a [String], stale: bool, number: &str) -> Result<&'a str, String> {
+    if suggestions.is_empty() {
+        return Err("выбирать не из чего: сначала выполните suggest".to_string());
+    }
+    if stale {
+        return Err("список подходящих слов изменился после suggest: выполните suggest снова".to_string());
+    }
+    match number.parse::<usize>() {
+        Ok(n @ 1..) if n <= suggestions.len() => Ok(&suggestions[n - 1]),
+        _ => Err(format!(
+            "номер догадки должен быть от 1 до {}, а не '{}'",
+            suggestions.len(),
+            number
+        )),
     }
-    true
 }
 
-fn matches_pattern(word: &str, guess: &str, pattern: &str) -> bool {
-    let word_chars: Vec<char> = word.chars().collect();
-    let guess_chars: Vec<char> = guess.chars().collect();
-    let pattern_chars: Vec<char> = pattern.chars().collect();
+/// Сколько слов на одной странице `show`.
+const SHOW_PAGE: usize = 50;
 
-    if word_chars.len() != guess_chars.len() || guess_chars.len() != pattern_chars.len() {
-        return false;
+/// Какую страницу показать по аргументу `show`, если сейчас показана `current` (с нуля) из списка в `total`
+/// слов. `None` — весь список (`show all`).
+fn select_page(argument: &str, current: usize, total: usize) -> Result<Option<usize>, String> {
+    let pages = page_count(total, SHOW_PAGE);
+    match argument.to_lowercase().as_str() {
+        "" => Ok(Some(0)),
+        "all" => Ok(None),
+        "more" if current + 1 < pages => Ok(Some(current + 1)),
+        "more" => Err("это последняя страница; show — вернуться к первой".to_string()),
+        number => match number.parse::<usize>() {
+            Ok(page @ 1..) if page <= pages => Ok(Some(page - 1)),
+            Ok(page) => Err(format!("страницы {} нет: всего страниц {}", page, pages)),
+            Err(_) => Err(format!("ожидается more, all или номер страницы, а не '{}'", argument)),
+        },
     }
+}
 
-    let mut used_in_word = vec![false; word_chars.len()];
+/// Сколько слов показывать в выводе `try`.
+const TRY_PREVIEW: usize = 10;
 
-    if !check_greens(&word_chars, &guess_chars, &pattern_chars, &mut used_in_word) {
-        return false;
+/// Команда `try`: применяет `догадка шаблон` к копии `solver` и описывает, что осталось бы.
+fn try_guess(solver: &Solver, input: &str, length: usize, map: &PatternMap) -> Result<String, String> {
+    let Some((guess, pattern)) = input.split_once(char::is_whitespace) else {
+        return Err("укажите догадку и шаблон через пробел, например: try crate bgybb".to_string());
+    };
+    let guess = solver.case().fold_word(guess);
+    let pattern = normalize_pattern_with(pattern.trim(), map);
+    validate_guess(&guess, length).and_then(|_| validate_pattern_with(&guess, &pattern, solver.case()))?;
+    let mut preview = solver.clone();
+    preview.apply(&guess, &pattern);
+    Ok(format_preview(preview.filtered(), TRY_PREVIEW))
+}
+
+/// Первая догадка на нетронутом словаре для `difficulty`: из кэша первых догадок или посчитанная заново.
+fn fresh_opener(solver: &Solver, args: &Args) -> Option<String> {
+    let mut fresh = solver.clone();
+    fresh.reset();
+    cached_opener(&fresh, args)
+        .map(|opener| opener.best.word)
+        .or_else(|| fresh.best_guess_by(args.strategy))
+}
+
+/// Ответ из списка ответов, который совпадает с `word` при сравнении букв по правилу решателя: с `--keep-case`
+/// `polish` находит `Polish`, а с `--case-sensitive` — только само `polish`.
+fn find_answer<'a>(solver: &'a Solver, word: &str) -> Option<&'a String> {
+    let case = solver.case();
+    let word = case.fold_word(word);
+    solver.answers().iter().find(|answer| case.fold_word(answer) == word)
+}
+
+/// Решатель для самоигры (`--simulate`, `difficulty`): те же словарь, ответы и сравнение букв, что у `solver`,
+/// но без частот, чтобы результат не зависел от `--frequency`.
+fn self_play_solver(solver: &Solver) -> Solver {
+    let mut self_play = Solver::with_answers(solver.dictionary().to_vec(), solver.answers().to_vec());
+    self_play.set_case(solver.case());
+    self_play
+}
+
+/// Самоигра против `word` с нетронутого поля (`difficulty` и `--difficulty`); первой играется `opener()`,
+/// который вызывается, только если слово есть среди ответов.
+fn difficulty(
+    solver: &Solver,
+    word: &str,
+    opener: impl FnOnce() -> Option<String>,
+    args: &Args,
+) -> Result<String, String> {
+    let Some(word) = find_answer(solver, word) else {
+        return Err(format!(
+            "слова '{}' нет среди возможных ответов",
+            solver.case().fold_word(word)
+        ));
+    };
+    let opener = opener();
+    let played = play_on(&self_play_solver(solver), word, opener.as_deref(), args.strategy);
+    Ok(format_difficulty(word, &played, solver.case()))
+}
+
+/// Завершает программу, если выбран режим, для которого она собрана без нужной фичи.
+fn check_features(args: &Args) {
+    let modes = [
+        (args.mmap, "--mmap", "mmap", cfg!(feature = "mmap")),
+        (args.tui, "--tui", "tui", cfg!(feature = "tui")),
+        (args.serve, "--serve", "server", cfg!(feature = "server")),
+    ];
+    for (used, flag, feature, enabled) in modes {
+        if used && !enabled {
+            eprintln!(
+                "Ошибка: {} доступен только в сборке с фичей {} (cargo build --features {})",
+                flag, feature, feature
+            );
+            process::exit(1);
+        }
     }
-    if !check_yellows(&word_chars, &guess_chars, &pattern_chars, &mut used_in_word) {
-        return false;
+}
+
+/// Читается ли словарь или список ответов со стандартного ввода (`--dictionary -`).
+fn dictionary_from_stdin(args: &Args) -> bool {
+    args.dictionary
+        .iter()
+        .chain(&args.answers)
+        .any(|path| path == STDIN_PATH)
+}
+
+/// Со словарём из стандартного ввода нельзя включать режимы, которые сами читают стандартный ввод.
+fn check_stdin_dictionary(args: &Args) {
+    if !dictionary_from_stdin(args) {
+        return;
     }
-    if !check_blacks(&word_chars, &guess_chars, &pattern_chars, &used_in_word) {
-        return false;
+    let modes = [
+        (args.interactive, "--interactive"),
+        (args.stdin, "--stdin"),
+        (args.tui, "--tui"),
+        (args.play, "--play"),
+        (args.absurdle, "--absurdle"),
+        (args.boards.is_some(), "--boards"),
+        (args.mmap, "--mmap"),
+    ];
+    if let Some((_, flag)) = modes.iter().find(|(used, _)| *used) {
+        eprintln!(
+            "Ошибка: словарь «-» читается со стандартного ввода, поэтому его нельзя сочетать с {}",
+            flag
+        );
+        process::exit(1);
     }
+}
+
+/// Подставляет значения из `wordle-solver.toml` туда, где флаг не задан в командной строке.
+fn apply_config(args: &mut Args, matches: &ArgMatches) {
+    let Some(path) = Config::find() else {
+        return;
+    };
+    let fail = |e: String| -> ! {
+        eprintln!("Ошибка: {}: {}", path.display(), e);
+        process::exit(1);
+    };
+    let config = Config::load(&path).unwrap_or_else(|e| fail(e.to_string()));
+    let from_cli = |id: &str| matches.value_source(id) == Some(ValueSource::CommandLine);
 
-    true
+    if args.dictionary.is_empty() {
+        args.dictionary = config.dictionary.into_iter().collect();
+    }
+    // С --mmap ответы и частоты не поддерживаются, поэтому из настроек они не берутся.
+    if args.answers.is_none() && !args.mmap {
+        args.answers = config.answers;
+    }
+    if args.frequency.is_none() && !args.mmap {
+        args.frequency = config.frequency;
+    }
+    if let Some(color) = config.color.filter(|_| !from_cli("color")) {
+        args.color = ColorMode::from_str(&color, true).unwrap_or_else(|e| fail(format!("color: {}", e)));
+    }
+    if let Some(strategy) = config.strategy.filter(|_| !from_cli("strategy")) {
+        args.strategy = strategy.parse().unwrap_or_else(|e| fail(format!("strategy: {}", e)));
+    }
 }
 
 fn main() -> io::Result<()> {
-    let mut args = Args::parse();
-    let dictionary = load_dictionary(&args.dictionary)?;
+    let result = run();
+    if let Some(summary) = profile::summary() {
+        eprintln!("{}", summary);
+    }
+    result
+}
+
+fn run() -> io::Result<()> {
+    let matches = Args::command().get_matches();
+    let mut args = Args::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    if args.profile {
+        profile::enable();
+    }
+    apply_config(&mut args, &matches);
+    check_features(&args);
+    check_stdin_dictionary(&args);
+    if args.max_guesses == 0 {
+        eprintln!("Ошибка: --max-guesses должно быть не меньше 1");
+        process::exit(1);
+    }
+    let log_level = if args.verbose { "wordle_solver=debug" } else { "warn" };
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or(log_level))
+        .format_timestamp(None)
+        .init();
+    if let Some(Command::Completions { shell }) = args.command {
+        clap_complete::generate(shell, &mut Args::command(), "wordle-solver", &mut io::stdout());
+        return Ok(());
+    }
+    if args.list_embedded {
+        for (name, text) in EMBEDDED_DICTIONARIES {
+            println!("{}{} — {} слов", EMBEDDED_PREFIX, name, parse_dictionary(text).len());
+        }
+        return Ok(());
+    }
+
+    let options = DictionaryOptions {
+        keep_case: args.keep_case || args.case_sensitive,
+        allow_nonalpha: args.allow_nonalpha,
+    };
+    #[cfg(feature = "mmap")]
+    if args.mmap {
+        filter_mapped(&args, options);
+        return Ok(());
+    }
+    let mut dictionary = profile::time(LOAD_STAGE, || load_dictionaries_or_exit(&args.dictionary, options));
+    let mut answers = match args.answers.as_deref() {
+        Some(path) => profile::time(LOAD_STAGE, || load_words_or_exit(Some(path), "--answers", options)),
+        None => dictionary.clone(),
+    };
+    let length = match args.length {
+        Some(length) => length,
+        None => {
+            let length = modal_length(&answers).unwrap_or(5);
+            if dictionary
+                .iter()
+                .chain(&answers)
+                .any(|word| word.chars().count() != length)
+            {
+                warn_mixed_lengths(length);
+            }
+            length
+        }
+    };
+    filter_by_length(&mut dictionary, length);
+    filter_by_length(&mut answers, length);
+    let mut solver = Solver::with_answers(dictionary, answers);
+    if let Some(path) = args.frequency.as_deref() {
+        let (frequencies, malformed) =
+            load_frequencies_with(path, options).unwrap_or_else(|e| exit_on_frequency_error(path, e));
+        for line in malformed {
+            eprintln!(
+                "Предупреждение: {}:{}: ожидается «слово число», строка пропущена",
+                path, line
+            );
+        }
+        solver.set_frequencies(frequencies);
+    }
+    solver.set_answers_only(args.answers_only);
+    if args.weighted && args.frequency.is_none() {
+        eprintln!("Предупреждение: --weighted без --frequency ничего не меняет");
+    }
+    solver.set_weighted(args.weighted);
+    solver.set_case(letter_case(&args));
+    check_first_guesses(&mut args, &solver);
+
+    if args.compute_opener {
+        compute_opener(&solver, &args);
+        return Ok(());
+    }
+
+    if args.play {
+        let seed = match (args.seed, args.date.as_deref()) {
+            (Some(seed), _) => seed,
+            (None, Some(date)) => parse_date(date).unwrap_or_else(|e| {
+                eprintln!("Ошибка: {}", e);
+                process::exit(1);
+            }) as u64,
+            (None, None) => today(),
+        };
+        let Some(answer) = pick_answer(solver.answers(), seed) else {
+            eprintln!("Ошибка: список ответов пуст");
+            process::exit(1);
+        };
+        return play_game(&solver, answer, &args);
+    }
+
+    if let Some(answer) = args.answer.as_deref() {
+        let Some(answer) = find_answer(&solver, answer).cloned() else {
+            eprintln!(
+                "Ошибка: слова '{}' нет среди возможных ответов",
+                solver.case().fold_word(answer)
+            );
+            process::exit(1);
+        };
+        auto_solve(&mut solver, &answer, &args);
+        return Ok(());
+    }
+
+    if let Some(word) = args.difficulty.as_deref() {
+        match difficulty(&solver, word, || fresh_opener(&solver, &args), &args) {
+            Ok(report) => println!("{}", report),
+            Err(e) => {
+                eprintln!("Ошибка: {}", e);
+                process::exit(1);
+            }
+        }
+        return Ok(());
+    }
 
-    if args.guess.is_none() && args.pattern.is_none() {
+    #[cfg(feature = "server")]
+    if args.serve {
+        return server::run(solver, length, args.port, args.max_candidates_for_suggest);
+    }
+
+    if args.absurdle {
+        return play_absurdle(&solver, &args);
+    }
+
+    if let Some(count) = args.boards {
+        if count == 0 {
+            eprintln!("Ошибка: --boards должно быть не меньше 1");
+            process::exit(1);
+        }
+        return solve_boards(
+            Boards::new(count, solver.dictionary(), solver.answers()),
+            length,
+            &args.pattern_map,
+        );
+    }
+
+    if args.simulate {
+        let self_play = self_play_solver(&solver);
+        let targets = sample(solver.answers(), args.sample);
+        let with_opener = !args.first_guess.is_empty();
+        let mut csv = args
+            .bench_csv
+            .as_deref()
+            .map(|path| create_bench_csv(path, with_opener));
+        let mut csv_error = None;
+        let mut games = 0;
+        let openers: Vec<Option<&str>> = if with_opener {
+            args.first_guess.iter().map(|word| Some(word.as_str())).collect()
+        } else {
+            vec![None]
+        };
+        for (i, opener) in openers.into_iter().enumerate() {
+            let on_game = |answer: &str, guesses: &[String]| {
+                if args.emoji {
+                    println!(
+                        "{}: {}\n",
+                        answer,
+                        emoji_grid(answer, guesses, self_play.case(), args.max_guesses)
+                    );
+                }
+                if let Some(writer) = &mut csv
+                    && csv_error.is_none()
+                {
+                    let solved = is_solved(answer, guesses, self_play.case()) && guesses.len() <= args.max_guesses;
+                    let opener = opener.map(|word| format!("{},", csv_field(word))).unwrap_or_default();
+                    let row = writeln!(writer, "{}{},{},{}", opener, csv_field(answer), guesses.len(), solved);
+                    csv_error = row.err();
+                }
+            };
+            let stats = match opener {
+                Some(word) => simulate_on(&self_play, &targets, Some(word), args.strategy, on_game),
+                None => {
+                    let opener = self_play.best_guess_by(args.strategy);
+                    simulate_on(&self_play, &targets, opener.as_deref(), args.strategy, on_game)
+                }
+            };
+            games += stats.games;
+            if let Some(word) = opener {
+                if i > 0 {
+                    println!();
+                }
+                println!("Первая догадка: {}", word);
+            }
+            print_simulation(&stats);
+        }
+        if let (Some(path), Some(mut writer)) = (args.bench_csv.as_deref(), csv) {
+            if let Some(e) = csv_error.or_else(|| writer.flush().err()) {
+                eprintln!("Ошибка: не удалось записать {}: {}", path, e);
+                process::exit(1);
+            }
+            eprintln!("Результаты {} партий записаны в {}.", games, path);
+        }
+        return Ok(());
+    }
+
+    #[cfg(feature = "tui")]
+    if args.tui {
+        if io::stdout().is_terminal() {
+            return tui::run(&mut solver, length, args.hard);
+        }
+        eprintln!("Предупреждение: для --tui нужен терминал, используется обычный интерактивный режим");
+        args.interactive = true;
+    }
+
+    if args.guess.is_empty()
+        && args.pattern.is_empty()
+        && !args.stdin
+        && args.replay.is_none()
+        && args.output.is_none()
+        && !args.count
+        && !args.fail_on_empty
+        && args.green.is_empty()
+        && args.yellow.is_empty()
+        && args.exclude.is_none()
+        && args.include.is_none()
+        && batch_fragments(&args).is_empty()
+        && !args.suggest
+        && !args.hint
+        && !args.freq
+    {
+        if args.no_interactive_fallback {
+            eprintln!(
+                "Ошибка: не задано ни одного фильтра (--guess/--pattern, --stdin и т. п.), а интерактивный режим отключён"
+            );
+            process::exit(1);
+        }
+        if dictionary_from_stdin(&args) {
+            eprintln!(
+                "Ошибка: не задано ни одного фильтра, а интерактивный режим недоступен: стандартный ввод занят словарём"
+            );
+            process::exit(1);
+        }
         args.interactive = true;
     }
 
     if args.interactive {
+        let color = args.color.enabled();
         println!(
             "Решатель Wordle: фильтрует слова по подсказкам\n\
-            Введите вашу догадку и шаблон результата (pattern), который выдал Wordle\n\
-            Команды:\n\
-            show  — показать текущий список подходящих слов\n\
-            new   — сбросить фильтр к исходному словарю\n\
-            exit  — выйти из программы\n"
+            Введите вашу догадку и шаблон результата (pattern), который выдал Wordle,\n\
+            по отдельности или одной строкой через пробел (например: crate bgybb)\n"
         );
-        let stdin = io::stdin();
-        let mut stdout = io::stdout();
-        let mut filtered: Vec<String> = dictionary.clone();
+        println!("{}", INTERACTIVE_HELP);
+        if args.replay.is_some() {
+            let history = batch_history(&args, length);
+            replay_history(&mut solver, &history, &args, |line| println!("{}", line));
+            println!(
+                "Повторено догадок из --replay: {}. Подходит {} слов.",
+                history.len(),
+                solver.filtered().len()
+            );
+        } else if !args.guess.is_empty() {
+            let history = batch_history(&args, length);
+            apply_history(&mut solver, &history, &args);
+            println!(
+                "Применено догадок из --guess/--pattern: {}. Подходит {} слов.",
+                history.len(),
+                solver.filtered().len()
+            );
+        }
 
+        let mut prompter = Prompter::new();
+        let mut rng = Rng::new(args.seed.unwrap_or_else(random_seed));
+        let difficulty_opener = OnceCell::new();
+        let mut show_page = 0;
+        // Догадки последнего `suggest` для `pick` и список подходящих слов, для которого они посчитаны:
+        // после новой догадки или любой другой смены списка они устаревают.
+        let mut last_suggestions: Vec<String> = Vec::new();
+        let mut suggested_for: Vec<String> = Vec::new();
         loop {
-            print!("Введите guess (или команду show/new/exit): ");
-            stdout.flush()?;
-            let mut input = String::new();
-            stdin.read_line(&mut input)?;
-            let input = input.trim();
+            let input = prompter.prompt("Введите guess (или команду, help — список команд): ")?;
 
-            if input.eq_ignore_ascii_case("exit") || input.is_empty() {
-                break;
-            } else if input.eq_ignore_ascii_case("show") {
-                println!("Подходит {} слов:", filtered.len());
-                for word in &filtered {
-                    println!("{}", word);
+            let picked;
+            let input = match parse_command_with(&input, &args.pattern_map) {
+                InteractiveCommand::Exit => break,
+                InteractiveCommand::Help => {
+                    println!("{}", INTERACTIVE_HELP);
+                    continue;
+                }
+                InteractiveCommand::Show(argument) => {
+                    let words = solver.ranked();
+                    match select_page(argument, show_page, words.len()) {
+                        Ok(None) => println!("{}", format_page(&words, 0, words.len().max(1))),
+                        Ok(Some(page)) => {
+                            show_page = page;
+                            println!("{}", format_page(&words, page, SHOW_PAGE));
+                        }
+                        Err(e) => println!("Ошибка: {}", e),
+                    }
+                    continue;
+                }
+                InteractiveCommand::New => {
+                    solver.reset();
+                    println!("Список слов сброшен. Всего {} слов.", solver.filtered().len());
+                    continue;
+                }
+                InteractiveCommand::Suggest => {
+                    last_suggestions = print_suggestions(&solver, &args);
+                    suggested_for = solver.filtered().to_vec();
+                    continue;
+                }
+                InteractiveCommand::Paste => {
+                    println!("Вставьте строки «догадка шаблон» (шаблон можно эмодзи); пустая строка — конец ввода.");
+                    let mut text = String::new();
+                    loop {
+                        let line = prompter.prompt("> ")?;
+                        if line.is_empty() {
+                            break;
+                        }
+                        text.push_str(&line);
+                        text.push('\n');
+                    }
+                    let history = match parse_history_with(text.as_bytes(), length, &args.pattern_map) {
+                        Ok(history) => history,
+                        Err(e) => {
+                            println!("Ошибка: {}; ничего не применено", e);
+                            continue;
+                        }
+                    };
+                    if let Err(e) = check_pasted_guesses(&solver, &history, &args) {
+                        println!("Ошибка: {}; ничего не применено", e);
+                        continue;
+                    }
+                    for (guess, pattern) in &history {
+                        solver.apply(guess, pattern);
+                    }
+                    println!(
+                        "Применено догадок: {}. Подходит {} слов.",
+                        history.len(),
+                        solver.filtered().len()
+                    );
+                    println!(
+                        "{}",
+                        format_attempts(solver.history().len(), args.max_guesses, solver.filtered().len())
+                    );
+                    if let Some(solution) = format_solution(solver.filtered()) {
+                        println!("{}", solution);
+                    }
+                    if solver.filtered().len() == 1 {
+                        println!("{}", SOLVED_HINT);
+                    }
+                    continue;
+                }
+                InteractiveCommand::Probe { all } => {
+                    let words = if all { solver.dictionary() } else { solver.filtered() };
+                    println!("Слова с наибольшим охватом букв (гласная — 2 очка, согласная — 1):");
+                    for (word, score) in rank_probes(words).into_iter().take(args.top) {
+                        println!("  {} — {}", word, score);
+                    }
+                    continue;
+                }
+                InteractiveCommand::Hint => {
+                    print_hint(&solver, &args);
+                    continue;
                 }
+                InteractiveCommand::Best => {
+                    match solver.best_blended(args.alpha) {
+                        Some(guess) => println!("{}", format_blended(&guess, args.alpha)),
+                        None => println!("Подсказать нечего: словарь пуст."),
+                    }
+                    continue;
+                }
+                InteractiveCommand::Undo => {
+                    if solver.undo() {
+                        println!("Последняя догадка отменена. Подходит {} слов.", solver.filtered().len());
+                    } else {
+                        println!("Нечего отменять.");
+                    }
+                    continue;
+                }
+                InteractiveCommand::Redo => {
+                    if solver.redo() {
+                        println!("Отменённое возвращено. Подходит {} слов.", solver.filtered().len());
+                    } else {
+                        println!("Нечего возвращать.");
+                    }
+                    continue;
+                }
+                InteractiveCommand::Known => {
+                    println!("{}", format_known(solver.constraints(), length));
+                    continue;
+                }
+                InteractiveCommand::History => {
+                    println!("{}", format_history(solver.history(), color));
+                    continue;
+                }
+                InteractiveCommand::Positions => {
+                    println!("{}", format_positions(solver.constraints(), length));
+                    continue;
+                }
+                InteractiveCommand::Freq => {
+                    println!("{}", format_frequencies(&letter_frequencies(solver.filtered())));
+                    continue;
+                }
+                InteractiveCommand::Heatmap => {
+                    println!("{}", format_heatmap(&letter_frequencies(solver.filtered())));
+                    continue;
+                }
+                InteractiveCommand::Stats => {
+                    println!("{}", format_stats(&solver));
+                    continue;
+                }
+                InteractiveCommand::Random => {
+                    match rng.pick(solver.filtered()) {
+                        Some(word) => println!("Случайное слово: {}", word),
+                        None => println!("Подходящих слов нет."),
+                    }
+                    continue;
+                }
+                InteractiveCommand::ByLetter(position) => {
+                    let position = if position.is_empty() {
+                        Ok(0)
+                    } else {
+                        parse_position(position, length)
+                    };
+                    match position {
+                        Ok(position) => {
+                            println!("{}", format_by_letter(&letter_frequencies(solver.filtered()), position))
+                        }
+                        Err(e) => println!("Ошибка: {}", e),
+                    }
+                    continue;
+                }
+                InteractiveCommand::Split(word) => {
+                    match validate_guess(word, length) {
+                        Ok(()) => println!(
+                            "{}",
+                            format_split(&partition_with(word, solver.filtered(), solver.case()))
+                        ),
+                        Err(e) => println!("Ошибка: {}", e),
+                    }
+                    continue;
+                }
+                InteractiveCommand::Try(input) => {
+                    match try_guess(&solver, input, length, &args.pattern_map) {
+                        Ok(preview) => println!("{}", preview),
+                        Err(e) => println!("Ошибка: {}", e),
+                    }
+                    continue;
+                }
+                InteractiveCommand::Expected(words) => {
+                    for word in words.split_whitespace() {
+                        let word = solver.case().fold_word(word);
+                        match validate_guess(&word, length) {
+                            Ok(()) => println!(
+                                "{}: в среднем останется {:.2} из {} слов",
+                                word,
+                                expected_remaining_with(&word, solver.filtered(), solver.case()),
+                                solver.filtered().len()
+                            ),
+                            Err(e) => println!("Ошибка: {}", e),
+                        }
+                    }
+                    continue;
+                }
+                InteractiveCommand::Difficulty(word) => {
+                    let opener = || difficulty_opener.get_or_init(|| fresh_opener(&solver, &args)).clone();
+                    match difficulty(&solver, word, opener, &args) {
+                        Ok(report) => println!("{}", report),
+                        Err(e) => println!("Ошибка: {}", e),
+                    }
+                    continue;
+                }
+                InteractiveCommand::Explain(word) => {
+                    let word = solver.case().fold_word(word);
+                    if let Err(e) = validate_guess(&word, length) {
+                        println!("Ошибка: {}", e);
+                        continue;
+                    }
+                    println!("{}", format_explanation(&word, solver.history(), solver.constraints()));
+                    if !solver.answers().contains(&word) {
+                        println!("Слова {} нет среди возможных ответов.", word);
+                    } else if solver.filtered().contains(&word) {
+                        println!("Слово {} в списке подходящих.", word);
+                    } else if solver.constraints().matches(&word) {
+                        println!("Слово {} убрано из подходящих командой regex или remove.", word);
+                    }
+                    continue;
+                }
+                InteractiveCommand::Check(word) => {
+                    let word = solver.case().fold_word(word);
+                    match validate_guess(&word, length) {
+                        Ok(()) => println!("{}", format_check(&word, solver.history(), solver.constraints())),
+                        Err(e) => println!("Ошибка: {}", e),
+                    }
+                    continue;
+                }
+                InteractiveCommand::Regex(expression) => {
+                    match word_regex(expression) {
+                        Ok(regex) => {
+                            let remaining = solver.retain(|word| regex.is_match(word));
+                            println!("Под выражение подходит {} слов.", remaining);
+                        }
+                        Err(e) => println!("Ошибка в регулярном выражении: {}", e),
+                    }
+                    continue;
+                }
+                InteractiveCommand::Anagram(letters) => {
+                    if let Err(e) = validate_letters(letters) {
+                        println!("Ошибка: {}", e);
+                        continue;
+                    }
+                    let found: Vec<&str> = solver
+                        .ranked()
+                        .into_iter()
+                        .map(String::as_str)
+                        .filter(|word| is_anagram(word, letters))
+                        .collect();
+                    if found.is_empty() {
+                        println!("Среди подходящих слов нет анаграмм {}.", letters.to_uppercase());
+                    } else {
+                        println!(
+                            "Анаграммы {} ({}): {}",
+                            letters.to_uppercase(),
+                            found.len(),
+                            found.join(", ")
+                        );
+                    }
+                    continue;
+                }
+                InteractiveCommand::Starts(letters) => {
+                    retain_fragment(&mut solver, Fragment::Prefix, letters);
+                    continue;
+                }
+                InteractiveCommand::Ends(letters) => {
+                    retain_fragment(&mut solver, Fragment::Suffix, letters);
+                    continue;
+                }
+                InteractiveCommand::Contains(letters) => {
+                    retain_fragment(&mut solver, Fragment::Substring, letters);
+                    continue;
+                }
+                InteractiveCommand::Green(placement) => {
+                    match parse_placement(placement, length) {
+                        Ok((position, letter)) => {
+                            let remaining = solver.set_green(position, letter, length);
+                            println!("Подходит {} слов.", remaining);
+                        }
+                        Err(e) => println!("Ошибка: {}", e),
+                    }
+                    continue;
+                }
+                InteractiveCommand::Yellow(placement) => {
+                    match parse_placement(placement, length) {
+                        Ok((position, letter)) => {
+                            let remaining = solver.add_yellow(position, letter, length);
+                            println!("Подходит {} слов.", remaining);
+                        }
+                        Err(e) => println!("Ошибка: {}", e),
+                    }
+                    continue;
+                }
+                InteractiveCommand::Exclude(letters) => {
+                    match validate_letters(letters) {
+                        Ok(()) => println!("Буквы исключены. Подходит {} слов.", solver.exclude(letters)),
+                        Err(e) => println!("Ошибка: {}", e),
+                    }
+                    continue;
+                }
+                InteractiveCommand::Include(letters) => {
+                    match validate_letters(letters) {
+                        Ok(()) => println!(
+                            "Буквы добавлены как обязательные. Подходит {} слов.",
+                            solver.include(letters)
+                        ),
+                        Err(e) => println!("Ошибка: {}", e),
+                    }
+                    continue;
+                }
+                InteractiveCommand::SessionSave(path) => {
+                    match Session::capture(&solver, dictionary_label(&args).as_deref()).save(path) {
+                        Ok(()) => println!("Сессия сохранена в {}.", path),
+                        Err(e) => println!("Не удалось сохранить сессию {}: {}", path, e),
+                    }
+                    continue;
+                }
+                InteractiveCommand::SessionLoad(path) => {
+                    let session = match Session::load(path) {
+                        Ok(session) => session,
+                        Err(e) => {
+                            println!("Не удалось загрузить сессию {}: {}", path, e);
+                            continue;
+                        }
+                    };
+                    let dictionary = dictionary_label(&args);
+                    if session.dictionary != dictionary {
+                        println!(
+                            "Предупреждение: сессия сохранена со словарём {}, сейчас используется {}",
+                            session.dictionary.as_deref().unwrap_or(DEFAULT_DICTIONARY),
+                            dictionary.as_deref().unwrap_or(DEFAULT_DICTIONARY)
+                        );
+                    }
+                    match session.restore(&mut solver) {
+                        Ok(remaining) => println!(
+                            "Сессия загружена: {} догадок, подходит {} слов.",
+                            session.history.len(),
+                            remaining
+                        ),
+                        Err(e) => println!("Ошибка в сессии {}: {}", path, e),
+                    }
+                    continue;
+                }
+                InteractiveCommand::Save(path) => {
+                    match save_words(path, solver.filtered()) {
+                        Ok(()) => println!("Сохранено {} слов в {}.", solver.filtered().len(), path),
+                        Err(e) => println!("Не удалось сохранить {}: {}", path, e),
+                    }
+                    continue;
+                }
+                InteractiveCommand::Report(path) => {
+                    match fs::write(
+                        path,
+                        format_report(&solver, args.strategy, args.max_candidates_for_suggest),
+                    ) {
+                        Ok(()) => println!("Отчёт о {} догадках записан в {}.", solver.history().len(), path),
+                        Err(e) => println!("Не удалось сохранить {}: {}", path, e),
+                    }
+                    continue;
+                }
+                InteractiveCommand::Remove(word) => {
+                    match solver.remove(word) {
+                        Ok(remaining) => println!("Слово {} убрано. Подходит {} слов.", word, remaining),
+                        Err(e) => println!("Ошибка: {}", e),
+                    }
+                    continue;
+                }
+                InteractiveCommand::Add(word) => {
+                    match solver.add(word) {
+                        Ok(remaining) => println!("Слово {} возвращено. Подходит {} слов.", word, remaining),
+                        Err(e) => println!("Ошибка: {}", e),
+                    }
+                    continue;
+                }
+                InteractiveCommand::Pick(number) => {
+                    match pick_suggestion(&last_suggestions, solver.filtered() != suggested_for.as_slice(), number) {
+                        Ok(word) => {
+                            println!("Догадка: {}", word);
+                            picked = word.to_string();
+                            &picked
+                        }
+                        Err(e) => {
+                            println!("Ошибка: {}", e);
+                            continue;
+                        }
+                    }
+                }
+                InteractiveCommand::Guess(input) => input,
+            };
+
+            let (guess, inline_pattern) = match parse_guess_input(input, length, &args.pattern_map) {
+                Ok(parsed) => parsed,
+                Err(e) => {
+                    println!("Ошибка: {}", e);
+                    continue;
+                }
+            };
+            if args.strict_guess
+                && let Err(e) = validate_known(guess, solver.dictionary(), CLOSEST_TOP)
+            {
+                println!("Ошибка: {}", e);
                 continue;
-            } else if input.eq_ignore_ascii_case("new") {
-                filtered = dictionary.clone();
-                println!("Список слов сброшен. Всего {} слов.", filtered.len());
+            }
+            if args.hard
+                && let Some(reason) = violates_hard_mode(guess, solver.history())
+            {
+                println!("Сложный режим: {}", reason);
                 continue;
             }
-
-            let guess = input;
-            print!("Введите pattern (прим. ybbgy): ");
-            stdout.flush()?;
-            let mut pattern = String::new();
-            stdin.read_line(&mut pattern)?;
-            let pattern = pattern.trim();
-
-            if pattern.is_empty() {
+            let pattern = match inline_pattern {
+                Some(pattern) => Some(pattern),
+                None => prompt_pattern(
+                    &mut prompter,
+                    "Введите pattern (прим. ybbgy): ",
+                    guess,
+                    &args.pattern_map,
+                )?,
+            };
+            let Some(pattern) = pattern else {
                 break;
-            }
-
-            filtered = filtered
-                .into_iter()
-                .filter(|word| matches_pattern(word, guess, pattern))
-                .collect();
+            };
 
-            println!("Подходит {} слов.", filtered.len());
+            if color {
+                println!("{}", render_colored(guess, &pattern));
+            }
+            let before_words = args.show_removed.map(|_| solver.filtered().to_vec());
+            let before = solver.filtered().len();
+            profile::time(FILTER_STAGE, || solver.apply(guess, &pattern));
+            last_suggestions.clear();
+            println!("{}", format_elimination(before, solver.filtered()));
+            if let (Some(limit), Some(before_words)) = (args.show_removed, before_words) {
+                println!(
+                    "{}",
+                    format_removed(&before_words, solver.filtered(), guess, &pattern, limit, solver.case())
+                );
+            }
+            if args.verbose {
+                println!(
+                    "{}",
+                    format_uncertainty(solver.answers().len(), before, solver.filtered().len())
+                );
+            }
+            println!(
+                "{}",
+                format_attempts(solver.history().len(), args.max_guesses, solver.filtered().len())
+            );
+            if solver.filtered().is_empty() {
+                let findings = diagnose_empty(solver.history());
+                if findings.is_empty() {
+                    println!("Прямых противоречий в подсказках нет: возможно, загаданного слова нет в словаре.");
+                } else {
+                    println!("Противоречия:");
+                    for finding in findings {
+                        println!("  - {}", finding);
+                    }
+                }
+                println!("Введите undo, чтобы отменить последнюю догадку.");
+            } else if solver.filtered().len() == 1 {
+                println!("{}", SOLVED_HINT);
+            }
         }
     } else {
-        let guess = args.guess.as_deref().expect("Не указан guess");
-        let pattern = args.pattern.as_deref().expect("Не указан pattern");
-        let filtered: Vec<_> = dictionary
-            .iter()
-            .filter(|word| matches_pattern(word, guess, pattern))
-            .collect();
+        let history = batch_history(&args, length);
+        let (greens, yellows) = batch_placements(&args, length);
+        if args.echo_history {
+            eprintln!("{}", format_history(&history, args.color.enabled()));
+        }
+        if args.replay.is_some() {
+            replay_history(&mut solver, &history, &args, |line| eprintln!("{}", line));
+        } else {
+            apply_history(&mut solver, &history, &args);
+        }
+        for (position, letter) in greens {
+            solver.set_green(position, letter, length);
+        }
+        for (position, letter) in yellows {
+            solver.add_yellow(position, letter, length);
+        }
+        if let Some(letters) = &args.exclude {
+            solver.exclude(letters);
+        }
+        if let Some(letters) = &args.include {
+            solver.include(letters);
+        }
+        for (fragment, letters) in batch_fragments(&args) {
+            solver.retain(|word| fragment.matches(word, &letters));
+        }
+        print_filtered(&solver.ranked(), &args, !history.is_empty());
+        if let Some(path) = &args.output {
+            write_words(path, solver.filtered());
+        }
 
-        println!("Подходит {} слов:", filtered.len());
-        for word in filtered {
-            println!("{}", word);
+        match args.format {
+            OutputFormat::Text | OutputFormat::Plain => {
+                if args.freq {
+                    println!("{}", format_frequencies(&letter_frequencies(solver.filtered())));
+                }
+                if args.suggest {
+                    print_suggestions(&solver, &args);
+                }
+                if args.hint {
+                    print_hint(&solver, &args);
+                }
+            }
+            OutputFormat::Json => {
+                let mut result = FilterResult::new(solver.filtered());
+                if args.freq {
+                    result.frequencies = Some(letter_frequencies(solver.filtered()));
+                }
+                if args.suggest {
+                    result.suggestions = Some(json_suggestions(&solver, &args));
+                }
+                if args.hint {
+                    result.hint = json_hint(&solver, &args);
+                }
+                println!("{}", serde_json::to_string(&result).map_err(io::Error::other)?);
+            }
+        }
+        if args.fail_on_empty && solver.filtered().is_empty() {
+            process::exit(EXIT_EMPTY);
         }
     }
 
     Ok(())
-}
\ No newline at end of file
+}
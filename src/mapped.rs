@@ -0,0 +1,122 @@
+use crate::dictionary::{DictionaryOptions, modal_length_of};
+use memmap2::Mmap;
+use std::fs::File;
+use std::io;
+use std::ops::Range;
+use std::path::Path;
+
+/// Слово словаря: байты в отображённом файле или, если при нормализации его пришлось изменить, отдельная строка.
+enum Word {
+    Mapped(Range<usize>),
+    Owned(usize),
+}
+
+/// Словарь, отображённый в память: слова не копируются в отдельные строки, а хранятся как диапазоны байтов
+/// файла. Нормализация та же, что у `load_dictionary_with`; отдельно хранятся только слова, которые нужно
+/// перевести в нижний регистр.
+pub struct MappedWords {
+    map: Mmap,
+    words: Vec<Word>,
+    owned: Vec<String>,
+    rejected: usize,
+}
+
+impl MappedWords {
+    pub fn open<P: AsRef<Path>>(path: P, options: DictionaryOptions) -> io::Result<Self> {
+        let file = File::open(path)?;
+        // SAFETY: отображение только читается; файл не должен меняться, пока программа работает.
+        let map = unsafe { Mmap::map(&file)? };
+        let text = std::str::from_utf8(&map).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let mut words = Vec::new();
+        let mut owned = Vec::new();
+        let mut rejected = 0;
+        let mut offset = 0;
+        for (i, line) in text.split_inclusive('\n').enumerate() {
+            let start = offset;
+            offset += line.len();
+            let line = if i == 0 {
+                line.trim_start_matches('\u{feff}')
+            } else {
+                line
+            };
+            let word = line.trim();
+            if word.is_empty() {
+                continue;
+            }
+            if !options.allow_nonalpha && !word.chars().all(char::is_alphabetic) {
+                rejected += 1;
+                continue;
+            }
+            if options.keep_case || word.chars().all(|c| c.to_lowercase().eq([c])) {
+                let begin = start + (word.as_ptr() as usize - text[start..].as_ptr() as usize);
+                words.push(Word::Mapped(begin..begin + word.len()));
+            } else {
+                words.push(Word::Owned(owned.len()));
+                owned.push(word.to_lowercase());
+            }
+        }
+
+        let mut mapped = MappedWords {
+            map,
+            words,
+            owned,
+            rejected,
+        };
+        mapped.dedup();
+        Ok(mapped)
+    }
+
+    /// Убирает повторы, сохраняя первое вхождение, без временных копий слов.
+    fn dedup(&mut self) {
+        let mut order: Vec<usize> = (0..self.words.len()).collect();
+        order.sort_by(|&a, &b| self.bytes(a).cmp(self.bytes(b)).then(a.cmp(&b)));
+        let mut keep = vec![true; self.words.len()];
+        for pair in order.windows(2) {
+            if self.bytes(pair[0]) == self.bytes(pair[1]) {
+                keep[pair[1]] = false;
+            }
+        }
+        let mut keep = keep.into_iter();
+        self.words.retain(|_| keep.next().unwrap_or(true));
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Число строк, пропущенных из-за символов не букв (как у `load_dictionary_with`).
+    pub fn rejected(&self) -> usize {
+        self.rejected
+    }
+
+    /// Байты слова: сравнивать их быстрее, чем строки, а порядок у UTF-8 тот же.
+    fn bytes(&self, id: usize) -> &[u8] {
+        match &self.words[id] {
+            Word::Mapped(range) => &self.map[range.clone()],
+            Word::Owned(index) => self.owned[*index].as_bytes(),
+        }
+    }
+
+    pub fn get(&self, id: usize) -> &str {
+        match &self.words[id] {
+            // Диапазоны взяты из уже проверенного UTF-8 текста по границам символов.
+            Word::Mapped(range) => std::str::from_utf8(&self.map[range.clone()]).unwrap_or_default(),
+            Word::Owned(index) => &self.owned[*index],
+        }
+    }
+
+    /// Самая частая длина слова (как `modal_length`).
+    pub fn modal_length(&self) -> Option<usize> {
+        modal_length_of(self.iter())
+    }
+
+    /// Слова в порядке файла.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        (0..self.len()).map(|id| self.get(id))
+    }
+}
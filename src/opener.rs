@@ -0,0 +1,108 @@
+use crate::solver::Solver;
+use crate::suggest::{Strategy, Suggestion, worst_case_with};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs::File;
+#[cfg(not(target_arch = "wasm32"))]
+use std::io::{self, Write};
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+
+/// Лучшая первая догадка и список лучших догадок на нетронутом словаре.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Opener {
+    pub best: Suggestion,
+    pub top: Vec<Suggestion>,
+}
+
+impl Opener {
+    /// Считает первую догадку по стратегии и `top` лучших догадок для ещё не отфильтрованного `solver`.
+    /// `None`, если словарь пуст.
+    pub fn compute(solver: &Solver, strategy: Strategy, top: usize) -> Option<Self> {
+        let word = solver.best_guess_by(strategy)?;
+        let best = Suggestion {
+            entropy: solver.guess_entropy(&word),
+            worst_case: worst_case_with(&word, solver.filtered(), solver.case()),
+            candidate: solver.filtered().contains(&word),
+            word,
+        };
+        Some(Opener {
+            best,
+            top: solver.suggestions(strategy, top),
+        })
+    }
+}
+
+/// Кэш первых догадок (`--compute-opener`): по записи на содержимое словаря, стратегию, `--answers-only`
+/// и `--weighted`.
+/// Запись используется, только пока словарь, ответы и частоты слов не изменились.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OpenerCache {
+    entries: BTreeMap<String, Opener>,
+}
+
+impl OpenerCache {
+    /// Запись для `solver`, если к нему ещё не применено ни одной догадки или правки списка.
+    pub fn get(&self, solver: &Solver, strategy: Strategy) -> Option<&Opener> {
+        let fresh = solver.history().is_empty()
+            && solver.constraints().is_empty()
+            && solver.filtered().len() == solver.answers().len();
+        if !fresh {
+            return None;
+        }
+        self.entries.get(&cache_key(solver, strategy))
+    }
+
+    /// Запоминает `opener` для словаря `solver`, заменяя прежнюю запись.
+    pub fn insert(&mut self, solver: &Solver, strategy: Strategy, opener: Opener) {
+        self.entries.insert(cache_key(solver, strategy), opener);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save<P: AsRef<Path>>(&self, filename: P) -> io::Result<()> {
+        let mut file = File::create(filename)?;
+        serde_json::to_writer_pretty(&mut file, self).map_err(io::Error::other)?;
+        writeln!(file)
+    }
+
+    /// Читает кэш; если файла нет, кэш пуст.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load<P: AsRef<Path>>(filename: P) -> io::Result<Self> {
+        match File::open(filename) {
+            Ok(file) => serde_json::from_reader(io::BufReader::new(file))
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(OpenerCache::default()),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+fn cache_key(solver: &Solver, strategy: Strategy) -> String {
+    let pool = if solver.answers_only() { ":answers-only" } else { "" };
+    let weighted = if solver.weighted() { ":weighted" } else { "" };
+    format!("{:016x}:{}{}{}", dictionary_hash(solver), strategy, pool, weighted)
+}
+
+/// Хэш FNV-1a содержимого словаря: допустимые догадки, ответы и частоты слов, в порядке словаря.
+pub fn dictionary_hash(solver: &Solver) -> u64 {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    let mut hash = OFFSET;
+    let mut feed = |bytes: &[u8]| {
+        for &byte in bytes {
+            hash = (hash ^ u64::from(byte)).wrapping_mul(PRIME);
+        }
+    };
+    for word in solver.dictionary() {
+        feed(word.as_bytes());
+        feed(&solver.frequency(word).to_le_bytes());
+        feed(b"\n");
+    }
+    feed(b"\0");
+    for word in solver.answers() {
+        feed(word.as_bytes());
+        feed(b"\n");
+    }
+    hash
+}
@@ -0,0 +1,612 @@
+use crate::analysis::{LetterFrequencies, letter_frequencies, rank_probes};
+use crate::boards::Boards;
+use crate::constraints::Constraints;
+use crate::pattern::{LetterCase, match_reason_with, score_guess_with};
+use crate::simulate::{Difficulty, MAX_GUESSES, is_solved};
+use crate::solver::Solver;
+use crate::suggest::{BlendedGuess, Strategy, Suggestion, SuggestionList};
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Результат фильтрации для машинного вывода (`--format json`). Подсказки, лучшая догадка и частоты букв
+/// заполняются только с `--suggest`, `--hint` и `--freq`; пустые поля в JSON не выводятся.
+#[derive(Debug, Serialize)]
+pub struct FilterResult {
+    pub count: usize,
+    pub words: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggestions: Option<SuggestionList>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequencies: Option<LetterFrequencies>,
+}
+
+impl FilterResult {
+    pub fn new(words: &[String]) -> Self {
+        FilterResult {
+            count: words.len(),
+            words: words.to_vec(),
+            suggestions: None,
+            hint: None,
+            frequencies: None,
+        }
+    }
+}
+
+const ANSI_GREEN: &str = "\x1b[30;42m";
+const ANSI_YELLOW: &str = "\x1b[30;43m";
+const ANSI_GRAY: &str = "\x1b[2;37m";
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// Догадка, раскрашенная по шаблону ANSI-кодами: зелёный фон для g, жёлтый для y, тусклый серый для b.
+pub fn render_colored(guess: &str, pattern: &str) -> String {
+    let mut rendered = String::new();
+    for (letter, color) in guess.chars().zip(pattern.chars()) {
+        let style = match color.to_ascii_lowercase() {
+            'g' => ANSI_GREEN,
+            'y' => ANSI_YELLOW,
+            _ => ANSI_GRAY,
+        };
+        rendered.push_str(&format!("{} {} {}", style, letter.to_uppercase(), ANSI_RESET));
+    }
+    rendered
+}
+
+/// Введённые за сессию догадки по порядку (команда `history`): с `color` шаблон показан цветами Wordle,
+/// иначе буквами g/y/b.
+pub fn format_history(history: &[(String, String)], color: bool) -> String {
+    if history.is_empty() {
+        return "Догадок пока нет.".to_string();
+    }
+    let mut lines = vec![format!("Догадки ({}):", history.len())];
+    lines.extend(history.iter().enumerate().map(|(i, (guess, pattern))| {
+        if color {
+            format!("{}. {}", i + 1, render_colored(guess, pattern))
+        } else {
+            format!("{}. {} {}", i + 1, guess, pattern)
+        }
+    }));
+    lines.join("\n")
+}
+
+/// Таблица подсказок с выровненными столбцами: номер, слово, энтропия в битах, размер наибольшей группы
+/// и отметка «да», если слово ещё может быть ответом.
+pub fn format_suggestions(suggestions: &[Suggestion]) -> String {
+    let width = suggestions
+        .iter()
+        .map(|suggestion| suggestion.word.chars().count())
+        .chain(["слово".chars().count()])
+        .max()
+        .unwrap_or(0);
+    let mut lines = vec![format!(
+        "{:>3}  {:<width$}  {:>6}  {:>6}  ответ",
+        "№", "слово", "бит", "группа"
+    )];
+    for (i, suggestion) in suggestions.iter().enumerate() {
+        let line = format!(
+            "{:>3}  {:<width$}  {:>6.3}  {:>6}  {}",
+            i + 1,
+            suggestion.word,
+            suggestion.entropy,
+            suggestion.worst_case,
+            if suggestion.candidate { "да" } else { "" }
+        );
+        lines.push(line.trim_end().to_string());
+    }
+    lines.join("\n")
+}
+
+/// Рекомендация команды `best` с частями оценки.
+pub fn format_blended(guess: &BlendedGuess, alpha: f64) -> String {
+    format!(
+        "Лучшая догадка: {}\n  энтропия: {:.3} бит\n  вероятность ответа: {:.2}\n  оценка: {:.2} × {:.3} + {:.2} × {:.2} = {:.3}",
+        guess.word,
+        guess.entropy,
+        guess.p_answer,
+        alpha,
+        guess.entropy,
+        1.0 - alpha,
+        guess.p_answer,
+        guess.score
+    )
+}
+
+/// Известные буквы в компактном виде: `_ R _ _ E`, затем обязательные (кроме уже зелёных) и исключённые буквы.
+pub fn format_known(constraints: &Constraints, length: usize) -> String {
+    let greens = constraints.greens();
+    let slots: Vec<String> = (0..length)
+        .map(|i| match greens.get(i).copied().flatten() {
+            Some(letter) => letter.to_uppercase().to_string(),
+            None => "_".to_string(),
+        })
+        .collect();
+
+    let must: Vec<String> = constraints
+        .min_counts()
+        .iter()
+        .filter(|&(&letter, &min)| min > greens.iter().filter(|&&green| green == Some(letter)).count())
+        .map(|(letter, _)| letter.to_string())
+        .collect();
+    let not: Vec<String> = constraints.excluded().map(|letter| letter.to_string()).collect();
+
+    format!("{}\nесть: {}\nнет: {}", slots.join(" "), must.join(","), not.join(","))
+}
+
+/// Где ещё может стоять каждая обязательная буква (команда `positions`): зелёные позиции и, если нужны ещё
+/// копии, свободные позиции, где буква не была жёлтой или чёрной. Позиции — с 1.
+pub fn format_positions(constraints: &Constraints, length: usize) -> String {
+    let join = |positions: &[usize]| {
+        positions
+            .iter()
+            .map(|i| (i + 1).to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let lines: Vec<String> = constraints
+        .min_counts()
+        .iter()
+        .filter(|&(_, &min)| min > 0)
+        .map(|(&letter, &min)| {
+            let (fixed, free): (Vec<usize>, Vec<usize>) = constraints
+                .possible_positions(letter, length)
+                .into_iter()
+                .partition(|&i| constraints.greens().get(i).copied().flatten() == Some(letter));
+            let letter = letter.to_uppercase();
+            match (fixed.is_empty(), min > fixed.len()) {
+                (true, _) if free.is_empty() => format!("{}: места нет — подсказки противоречат", letter),
+                (true, _) => format!("{}: позиции {}", letter, join(&free)),
+                (false, false) => format!("{}: на месте {}", letter, join(&fixed)),
+                (false, true) if free.is_empty() => {
+                    format!("{}: на месте {}, для ещё одной места нет", letter, join(&fixed))
+                }
+                (false, true) => format!(
+                    "{}: на месте {}, ещё одна — позиции {}",
+                    letter,
+                    join(&fixed),
+                    join(&free)
+                ),
+            }
+        })
+        .collect();
+    if lines.is_empty() {
+        return "Обязательных букв пока нет.".to_string();
+    }
+    lines.join("\n")
+}
+
+/// Почему слово подходит или нет: результат по каждой догадке из истории, затем по всем известным
+/// ограничениям вместе (включая заданные командами green, yellow, exclude и include).
+pub fn format_explanation(word: &str, history: &[(String, String)], constraints: &Constraints) -> String {
+    fn verdict(reason: Result<(), String>) -> String {
+        match reason {
+            Ok(()) => "подходит".to_string(),
+            Err(reason) => format!("не подходит: {}", reason),
+        }
+    }
+
+    if constraints.is_empty() {
+        return format!("{}: ограничений пока нет, подходит любое слово.", word);
+    }
+    let mut lines: Vec<String> = history
+        .iter()
+        .map(|(guess, pattern)| {
+            format!(
+                "{} {} — {}",
+                guess,
+                pattern,
+                verdict(match_reason_with(word, guess, pattern, constraints.case()))
+            )
+        })
+        .collect();
+    lines.push(format!("Все ограничения — {}", verdict(constraints.match_reason(word))));
+    lines.join("\n")
+}
+
+/// Короткий ответ команды `check`: подходит ли слово под все догадки (и ограничения команд), а если нет —
+/// первое нарушенное правило и догадка, которой оно задано. Словарь не нужен: проверить можно любое слово.
+pub fn format_check(word: &str, history: &[(String, String)], constraints: &Constraints) -> String {
+    if constraints.is_empty() {
+        return format!("{}: ограничений пока нет, подходит любое слово.", word);
+    }
+    let broken = history.iter().enumerate().find_map(|(i, (guess, pattern))| {
+        match_reason_with(word, guess, pattern, constraints.case())
+            .err()
+            .map(|reason| format!("догадка {} ({} {}): {}", i + 1, guess, pattern, reason))
+    });
+    match broken.map_or_else(|| constraints.match_reason(word), Err) {
+        Ok(()) => format!("{}: да, подходит под все догадки ({}).", word, history.len()),
+        Err(reason) => format!("{}: нет — {}", word, reason),
+    }
+}
+
+/// Итог применения догадки: сколько слов убрано и сколько осталось, затем `format_solution`. Если не осталось
+/// ни одного слова, выводится предупреждение о противоречивых подсказках.
+pub fn format_elimination(before: usize, remaining: &[String]) -> String {
+    let mut message = format!(
+        "Убрано {} слов, осталось {}.",
+        before.saturating_sub(remaining.len()),
+        remaining.len()
+    );
+    if remaining.is_empty() {
+        message.push_str("\nВнимание: ни одно слово не подходит — подсказки противоречат друг другу.");
+    } else if let Some(solution) = format_solution(remaining) {
+        message.push('\n');
+        message.push_str(&solution);
+    }
+    message
+}
+
+/// Первые `limit` слов, которые догадка `guess` с шаблоном `pattern` убрала из `before` (осталось `after`, в том
+/// же порядке), и правило, по которому убрано каждое (`--show-removed`); буквы сравниваются по правилу `case`.
+pub fn format_removed(
+    before: &[String],
+    after: &[String],
+    guess: &str,
+    pattern: &str,
+    limit: usize,
+    case: LetterCase,
+) -> String {
+    let mut remaining = after.iter().peekable();
+    let removed: Vec<&String> = before
+        .iter()
+        .filter(|word| {
+            if remaining.peek() == Some(word) {
+                remaining.next();
+                false
+            } else {
+                true
+            }
+        })
+        .collect();
+    if removed.is_empty() {
+        return "Ни одно слово не убрано.".to_string();
+    }
+    let mut lines = vec![format!(
+        "Убраны (показано {} из {}):",
+        removed.len().min(limit),
+        removed.len()
+    )];
+    lines.extend(removed.iter().take(limit).map(|word| {
+        let reason = match match_reason_with(word, guess, pattern, case) {
+            Err(reason) => reason,
+            // Слово подходит под эту догадку, значит, его убрали ограничения прежних догадок или команд.
+            Ok(()) => "не подходит под прежние ограничения".to_string(),
+        };
+        format!("  {} — {}", word, reason)
+    }));
+    lines.join("\n")
+}
+
+/// Сколько страниц по `page_size` слов займут `total` слов (хотя бы одна).
+pub fn page_count(total: usize, page_size: usize) -> usize {
+    total.div_ceil(page_size.max(1)).max(1)
+}
+
+/// Страница `page` (с нуля) списка `words` по `page_size` слов для `show`. Если страниц несколько, в заголовке
+/// указан номер страницы, а в конце — сколько слов ещё не показано и как их посмотреть.
+pub fn format_page(words: &[impl AsRef<str>], page: usize, page_size: usize) -> String {
+    let pages = page_count(words.len(), page_size);
+    let start = (page * page_size).min(words.len());
+    let end = (start + page_size).min(words.len());
+    let mut lines = vec![if pages > 1 {
+        format!("Подходит {} слов (страница {} из {}):", words.len(), page + 1, pages)
+    } else {
+        format!("Подходит {} слов:", words.len())
+    }];
+    lines.extend(words[start..end].iter().map(|word| word.as_ref().to_string()));
+    if end < words.len() {
+        lines.push(format!(
+            "Ещё {} слов: show more — следующая страница, show <номер> — любая, show all — все сразу.",
+            words.len() - end
+        ));
+    }
+    lines.join("\n")
+}
+
+/// Что осталось бы после пробной догадки (`try`): число слов и первые `limit` из них.
+pub fn format_preview(remaining: &[String], limit: usize) -> String {
+    if remaining.is_empty() {
+        return "Не осталось бы ни одного слова: подсказки противоречили бы друг другу.".to_string();
+    }
+    let mut message = format!(
+        "Осталось бы {} слов: {}",
+        remaining.len(),
+        remaining[..remaining.len().min(limit)].join(", ")
+    );
+    if remaining.len() > limit {
+        message.push_str(", …");
+    }
+    message
+}
+
+/// Отдельная строка, когда слов почти не осталось: единственное слово — это ответ, а из двух слов хватит ещё
+/// одной догадки. В остальных случаях `None`.
+pub fn format_solution(remaining: &[impl AsRef<str>]) -> Option<String> {
+    match remaining {
+        [answer] => Some(format!("Ответ: {}", answer.as_ref())),
+        [first, second] => Some(format!(
+            "Осталось два слова: {} или {}. Назовите одно из них — если не угадали, ответом будет второе.",
+            first.as_ref(),
+            second.as_ref()
+        )),
+        _ => None,
+    }
+}
+
+/// Сколько попыток из `max` истрачено после `used` догадок; на последней — предупреждение. Если попытки
+/// кончились, а подходящих слов не одно, об этом сообщается, но решать дальше можно.
+pub fn format_attempts(used: usize, max: usize, remaining: usize) -> String {
+    if used >= max && remaining != 1 {
+        return format!(
+            "Попытки закончились ({} из {}): в игре слово уже не угадать, но анализ можно продолжать.",
+            used, max
+        );
+    }
+    match max.saturating_sub(used) {
+        0 => format!("Попытка {} из {}.", used, max),
+        1 => format!("Попытка {} из {}. Внимание: осталась последняя попытка!", used, max),
+        left => format!("Попытка {} из {}, осталось {}.", used, max, left),
+    }
+}
+
+/// Трудность слова (команда `difficulty`): число догадок, метка и сама последовательность догадок `played`,
+/// которую сыграла стратегия. Если ответ не угадан (`is_solved` с правилом `case`) или догадок больше шести,
+/// слово не решено.
+pub fn format_difficulty(answer: &str, played: &[String], case: LetterCase) -> String {
+    let solved = is_solved(answer, played, case) && played.len() <= MAX_GUESSES;
+    let difficulty = if solved {
+        Difficulty::from_guesses(played.len())
+    } else {
+        Difficulty::Hard
+    };
+    let outcome = if solved {
+        format!("{} догадок", played.len())
+    } else {
+        format!("не решено за {} догадок", MAX_GUESSES)
+    };
+    format!("{}: {} ({})\n{}", answer, difficulty, outcome, played.join(" → "))
+}
+
+/// Итог по полям: у решённых — ответ, у остальных — число подходящих слов; в конце — сколько полей решено.
+pub fn format_boards(boards: &Boards) -> String {
+    let mut lines = Vec::new();
+    for board in 0..boards.len() {
+        let filtered = boards.board(board).filtered();
+        let state = match filtered {
+            [answer] => format!("решено — {}", answer),
+            [] => "нет подходящих слов".to_string(),
+            _ => format!("подходит {} слов", filtered.len()),
+        };
+        lines.push(format!("Поле {}: {}", board + 1, state));
+    }
+    let solved = (0..boards.len()).filter(|&board| boards.is_solved(board)).count();
+    lines.push(format!("Решено полей: {} из {}.", solved, boards.len()));
+    lines.join("\n")
+}
+
+/// Сколько бит неопределённости осталось: log2 числа подходящих слов из log2 исходного списка (`total`),
+/// и сколько бит дала последняя догадка (список сократился с `before` до `remaining`).
+pub fn format_uncertainty(total: usize, before: usize, remaining: usize) -> String {
+    if remaining == 0 {
+        return "Неопределённость: подходящих слов нет.".to_string();
+    }
+    let bits = |count: usize| (count.max(1) as f64).log2();
+    format!(
+        "Неопределённость: осталось {:.1} бит, разрешено {:.1} из {:.1} (последняя догадка дала {:.1} бит).",
+        bits(remaining),
+        bits(total) - bits(remaining),
+        bits(total),
+        bits(before) - bits(remaining)
+    )
+}
+
+/// Сводка по подходящим словам (команда `stats`): сколько их осталось, сколько бит неопределённости,
+/// самая частая буква на каждой позиции и, если загружена таблица частот, сколько из них частые слова.
+pub fn format_stats(solver: &Solver) -> String {
+    let remaining = solver.filtered();
+    if remaining.is_empty() {
+        return "Подходящих слов нет.".to_string();
+    }
+    let mut lines = vec![
+        format!("Подходит {} слов из {}.", remaining.len(), solver.answers().len()),
+        format!("Неопределённость: {:.1} бит.", (remaining.len() as f64).log2()),
+    ];
+    let positions: Vec<String> = letter_frequencies(remaining)
+        .positional
+        .iter()
+        .enumerate()
+        .filter_map(|(i, counts)| {
+            let (letter, count) = counts.iter().max_by(|a, b| a.1.cmp(b.1).then(b.0.cmp(a.0)))?;
+            Some(format!("{}: {} {}", i + 1, letter.to_uppercase(), count))
+        })
+        .collect();
+    lines.push(format!("Чаще всего по позициям: {}", positions.join(", ")));
+    if let Some(common) = solver.common_count() {
+        lines.push(format!("Частых слов: {} из {}.", common, remaining.len()));
+    }
+    lines.join("\n")
+}
+
+/// Частоты букв: строка с общими частотами по убыванию и таблица буква × позиция.
+pub fn format_frequencies(frequencies: &LetterFrequencies) -> String {
+    let overall: Vec<String> = frequencies
+        .sorted()
+        .iter()
+        .map(|(letter, count)| format!("{} {}", letter, count))
+        .collect();
+    let mut table = format!("Частоты букв: {}\n  ", overall.join(", "));
+    for i in 0..frequencies.positional.len() {
+        table.push_str(&format!("{:>6}", i + 1));
+    }
+    for letter in frequencies.overall.keys() {
+        table.push_str(&format!("\n{} ", letter));
+        for position in &frequencies.positional {
+            table.push_str(&format!("{:>6}", position.get(letter).copied().unwrap_or(0)));
+        }
+    }
+    table
+}
+
+/// Самая длинная полоска гистограммы в `format_by_letter`.
+const HISTOGRAM_WIDTH: usize = 40;
+
+/// Гистограмма букв на позиции `position` (с 0) среди подходящих слов (команда `byletter`): строки
+/// `буква: число` с полоской по убыванию числа слов; при равенстве — по алфавиту.
+pub fn format_by_letter(frequencies: &LetterFrequencies, position: usize) -> String {
+    let sorted = frequencies.ranked_at(position);
+    let Some(&(_, max)) = sorted.first() else {
+        return "Подходящих слов нет.".to_string();
+    };
+
+    let mut lines = vec![format!("Буквы на позиции {}:", position + 1)];
+    lines.extend(sorted.iter().map(|&(letter, count)| {
+        let bar = (count * HISTOGRAM_WIDTH).div_ceil(max);
+        format!("{}: {:>5} {}", letter.to_uppercase(), count, "█".repeat(bar))
+    }));
+    lines.join("\n")
+}
+
+/// Сколько самых частых букв на позицию показывает `format_heatmap`.
+const HEATMAP_TOP: usize = 3;
+
+/// Теплокарта для команды `heatmap`: столбцы — позиции, в строках — самые частые буквы на каждой позиции
+/// и доля подходящих слов с ними (число слов с буквой на позиции, делённое на число всех подходящих слов).
+pub fn format_heatmap(frequencies: &LetterFrequencies) -> String {
+    let Some(total) = frequencies
+        .positional
+        .first()
+        .map(|counts| counts.values().sum::<usize>())
+    else {
+        return "Подходящих слов нет.".to_string();
+    };
+    let mut lines = vec![format!("Вероятности букв по позициям ({} слов):", total)];
+    let header: String = (1..=frequencies.positional.len())
+        .map(|position| format!("{:<8}", position))
+        .collect();
+    lines.push(format!("   {}", header.trim_end()));
+    let ranked: Vec<Vec<(char, usize)>> = (0..frequencies.positional.len())
+        .map(|position| frequencies.ranked_at(position))
+        .collect();
+    for rank in 0..HEATMAP_TOP {
+        let row: String = ranked
+            .iter()
+            .map(|letters| match letters.get(rank) {
+                Some(&(letter, count)) => {
+                    format!("{} {:>3}%  ", letter.to_uppercase(), (count * 100 + total / 2) / total)
+                }
+                None => format!("{:<8}", "—"),
+            })
+            .collect();
+        if ranked.iter().any(|letters| letters.len() > rank) {
+            lines.push(format!("{}. {}", rank + 1, row.trim_end()));
+        }
+    }
+    lines.join("\n")
+}
+
+/// Шаблон в виде строки эмодзи, как в общей сетке Wordle: 🟩 — g, 🟨 — y, ⬛ — b.
+pub fn emoji_row(pattern: &str) -> String {
+    pattern
+        .chars()
+        .map(|color| match color.to_ascii_lowercase() {
+            'g' => '🟩',
+            'y' => '🟨',
+            _ => '⬛',
+        })
+        .collect()
+}
+
+/// Сетка эмодзи сыгранной партии против `answer` с заголовком `N/M`, где `M` — число попыток `max_guesses`
+/// (или `X/M`, если партия в них не уложилась или ответ не угадан). Шаблоны считаются со сравнением букв
+/// по правилу `case`.
+pub fn emoji_grid(answer: &str, guesses: &[String], case: LetterCase, max_guesses: usize) -> String {
+    let solved = is_solved(answer, guesses, case);
+    let score = if solved && guesses.len() <= max_guesses {
+        guesses.len().to_string()
+    } else {
+        "X".to_string()
+    };
+    let mut grid = format!("{}/{}", score, max_guesses);
+    for guess in guesses {
+        grid.push('\n');
+        grid.push_str(&emoji_row(&score_guess_with(answer, guess, case)));
+    }
+    grid
+}
+
+/// Разбиение кандидатов по шаблонам: строки `шаблон число` по убыванию размера группы и размер наибольшей группы.
+pub fn format_split(buckets: &HashMap<String, usize>) -> String {
+    let mut sorted: Vec<(&String, &usize)> = buckets.iter().collect();
+    sorted.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+
+    let mut lines: Vec<String> = sorted
+        .iter()
+        .map(|(pattern, count)| format!("{} {:>5}", pattern, count))
+        .collect();
+    let worst = sorted.first().map(|&(_, &count)| count).unwrap_or(0);
+    lines.push(format!("Групп: {}, наибольшая: {}", sorted.len(), worst));
+    lines.join("\n")
+}
+
+/// Отчёт о партии в Markdown (команда `report`): таблица ходов — догадка, шаблон эмодзи и буквами, сколько
+/// слов осталось и какую догадку подсказала бы стратегия `strategy` перед этим ходом, — затем ответ и число
+/// догадок. Ходы заново применяются к копии решателя, поэтому в отчёт попадают только пары «догадка шаблон».
+/// Если перед ходом подходит больше `max_candidates` слов (`0` — предела нет), догадка выбирается по охвату
+/// букв, как в `suggest` при `--max-candidates-for-suggest`.
+pub fn format_report(solver: &Solver, strategy: Strategy, max_candidates: usize) -> String {
+    let mut report = String::from(
+        "# Решение Wordle\n\n\
+        | # | Догадка | Шаблон | Осталось слов | Лучшая догадка перед ходом |\n\
+        |---|---------|--------|---------------|----------------------------|\n",
+    );
+    for (turn, ((guess, pattern), (before, remaining))) in solver.history().iter().zip(solver.turns()).enumerate() {
+        let replay = &before;
+        let best = if replay.too_many_candidates(max_candidates) {
+            match rank_probes(replay.filtered()).first() {
+                Some((word, score)) => format!("{} (охват букв {})", word, score),
+                None => "—".to_string(),
+            }
+        } else {
+            match replay.suggestions(strategy, 1).first() {
+                Some(suggestion) if !replay.filtered().is_empty() => {
+                    format!("{} ({:.2} бит)", suggestion.word, suggestion.entropy)
+                }
+                _ => "—".to_string(),
+            }
+        };
+        report.push_str(&format!(
+            "| {} | {} | {} `{}` | {} | {} |\n",
+            turn + 1,
+            guess,
+            emoji_row(pattern),
+            pattern.to_lowercase(),
+            remaining,
+            best
+        ));
+    }
+
+    let guesses = solver.history().len();
+    let solved = solver
+        .history()
+        .last()
+        .is_some_and(|(_, pattern)| pattern.chars().all(|color| color.eq_ignore_ascii_case(&'g')));
+    report.push('\n');
+    report.push_str(&match solver.filtered() {
+        _ if solved => format!(
+            "**Ответ:** {}, угадано за {} догадок.\n",
+            solver.history()[guesses - 1].0,
+            guesses
+        ),
+        [answer] => format!(
+            "**Ответ:** {} (найден после {} догадок, ещё не введён).\n",
+            answer, guesses
+        ),
+        words => format!(
+            "**Ответ не найден:** после {} догадок подходит {} слов.\n",
+            guesses,
+            words.len()
+        ),
+    });
+    report
+}
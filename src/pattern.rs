@@ -0,0 +1,522 @@
+use crate::encoded::{EncodedWord, MAX_CODED_LEN};
+
+pub(crate) fn is_color(pattern_char: char, color: char) -> bool {
+    pattern_char.eq_ignore_ascii_case(&color)
+}
+
+/// Буква в нижнем регистре по правилам Unicode (`É` → `é`, `Ñ` → `ñ`). Если нижний регистр буквы —
+/// не одна буква, она остаётся как есть, чтобы не менять длину слова.
+pub fn fold_letter(letter: char) -> char {
+    if letter.is_ascii() {
+        return letter.to_ascii_lowercase();
+    }
+    let mut lower = letter.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(folded), None) => folded,
+        _ => letter,
+    }
+}
+
+/// Как сравниваются буквы догадок, шаблонов и ограничений.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LetterCase {
+    /// Без учёта регистра (через `fold_letter`): `US` и `us` — одно и то же слово.
+    #[default]
+    Insensitive,
+    /// Точно, как записано (`--case-sensitive`): `US` и `us` — разные слова.
+    Sensitive,
+}
+
+impl LetterCase {
+    /// Буква в том виде, в каком она сравнивается.
+    pub fn fold(self, letter: char) -> char {
+        match self {
+            LetterCase::Insensitive => fold_letter(letter),
+            LetterCase::Sensitive => letter,
+        }
+    }
+
+    /// Одна и та же ли это буква.
+    pub fn same(self, a: char, b: char) -> bool {
+        a == b || self.fold(a) == self.fold(b)
+    }
+
+    /// Буквы слова в том виде, в каком они сравниваются.
+    pub fn letters(self, word: &str) -> Vec<char> {
+        word.chars().map(|letter| self.fold(letter)).collect()
+    }
+
+    /// Слово в том виде, в каком оно сравнивается: для Insensitive — в нижнем регистре.
+    pub fn fold_word(self, word: &str) -> String {
+        word.chars().map(|letter| self.fold(letter)).collect()
+    }
+}
+
+/// Буквы слова без учёта регистра (через `fold_letter`).
+pub(crate) fn letters(word: &str) -> Vec<char> {
+    LetterCase::Insensitive.letters(word)
+}
+
+pub(crate) fn letter_count(chars: &[char], letter: char, case: LetterCase) -> usize {
+    chars.iter().filter(|&&c| case.same(c, letter)).count()
+}
+
+/// Сколько копий буквы `letter` помечено в шаблоне зелёным или жёлтым.
+pub(crate) fn revealed_count(guess_chars: &[char], pattern_chars: &[char], letter: char, case: LetterCase) -> usize {
+    guess_chars
+        .iter()
+        .zip(pattern_chars)
+        .filter(|&(&c, &p)| case.same(c, letter) && (is_color(p, 'g') || is_color(p, 'y')))
+        .count()
+}
+
+/// Зелёная буква стоит в слове на той же позиции. Буквы здесь и ниже сравниваются по правилу `case`.
+pub fn check_greens(word_chars: &[char], guess_chars: &[char], pattern_chars: &[char], case: LetterCase) -> bool {
+    failed_green(word_chars, guess_chars, pattern_chars, case).is_none()
+}
+
+/// Жёлтая буква не стоит на своей позиции, и в слове её не меньше, чем зелёных и жёлтых копий в догадке.
+pub fn check_yellows(word_chars: &[char], guess_chars: &[char], pattern_chars: &[char], case: LetterCase) -> bool {
+    failed_yellow(word_chars, guess_chars, pattern_chars, case).is_none()
+}
+
+/// Чёрная буква не стоит на своей позиции, и в слове её ровно столько, сколько зелёных и жёлтых копий в догадке.
+pub fn check_blacks(word_chars: &[char], guess_chars: &[char], pattern_chars: &[char], case: LetterCase) -> bool {
+    failed_black(word_chars, guess_chars, pattern_chars, case).is_none()
+}
+
+/// Первая позиция, где нарушено правило `check_greens`.
+fn failed_green(word_chars: &[char], guess_chars: &[char], pattern_chars: &[char], case: LetterCase) -> Option<usize> {
+    (0..word_chars.len()).find(|&i| is_color(pattern_chars[i], 'g') && !case.same(word_chars[i], guess_chars[i]))
+}
+
+/// Первая позиция, где нарушено правило `check_yellows`.
+fn failed_yellow(word_chars: &[char], guess_chars: &[char], pattern_chars: &[char], case: LetterCase) -> Option<usize> {
+    (0..word_chars.len()).find(|&i| {
+        is_color(pattern_chars[i], 'y')
+            && (case.same(word_chars[i], guess_chars[i])
+                || letter_count(word_chars, guess_chars[i], case)
+                    < revealed_count(guess_chars, pattern_chars, guess_chars[i], case))
+    })
+}
+
+/// Первая позиция, где нарушено правило `check_blacks`.
+fn failed_black(word_chars: &[char], guess_chars: &[char], pattern_chars: &[char], case: LetterCase) -> Option<usize> {
+    (0..word_chars.len()).find(|&i| {
+        is_color(pattern_chars[i], 'b')
+            && (case.same(word_chars[i], guess_chars[i])
+                || letter_count(word_chars, guess_chars[i], case)
+                    != revealed_count(guess_chars, pattern_chars, guess_chars[i], case))
+    })
+}
+
+/// То же, что `matches_pattern`, но вместо `false` объясняет первое нарушенное правило:
+/// сначала зелёные, затем жёлтые, затем чёрные буквы шаблона.
+pub fn match_reason(word: &str, guess: &str, pattern: &str) -> Result<(), String> {
+    match_reason_with(word, guess, pattern, LetterCase::Insensitive)
+}
+
+/// `match_reason` со сравнением букв по правилу `case`.
+pub fn match_reason_with(word: &str, guess: &str, pattern: &str, case: LetterCase) -> Result<(), String> {
+    let word_chars = case.letters(word);
+    let guess_chars = case.letters(guess);
+    let pattern_chars: Vec<char> = pattern.chars().collect();
+    if word_chars.len() != guess_chars.len() || guess_chars.len() != pattern_chars.len() {
+        return Err(format!(
+            "в слове {} букв, а в догадке {} и в шаблоне {}",
+            word_chars.len(),
+            guess_chars.len(),
+            pattern_chars.len()
+        ));
+    }
+
+    if let Some(i) = failed_green(&word_chars, &guess_chars, &pattern_chars, case) {
+        return Err(format!(
+            "позиция {}: должна быть зелёная {}, а стоит {}",
+            i + 1,
+            guess_chars[i].to_uppercase(),
+            word_chars[i].to_uppercase()
+        ));
+    }
+    if let Some(i) = failed_yellow(&word_chars, &guess_chars, &pattern_chars, case) {
+        let letter = guess_chars[i];
+        let needed = revealed_count(&guess_chars, &pattern_chars, letter, case);
+        return Err(match letter_count(&word_chars, letter, case) {
+            _ if case.same(word_chars[i], letter) => format!(
+                "позиция {}: жёлтая {} не может стоять на этом месте",
+                i + 1,
+                letter.to_uppercase()
+            ),
+            0 => format!("нет обязательной буквы {}", letter.to_uppercase()),
+            count => format!(
+                "буква {} нужна минимум {} раз(а), а в слове {}",
+                letter.to_uppercase(),
+                needed,
+                count
+            ),
+        });
+    }
+    if let Some(i) = failed_black(&word_chars, &guess_chars, &pattern_chars, case) {
+        let letter = guess_chars[i];
+        let allowed = revealed_count(&guess_chars, &pattern_chars, letter, case);
+        return Err(if case.same(word_chars[i], letter) {
+            format!(
+                "позиция {}: чёрная {} не может стоять на этом месте",
+                i + 1,
+                letter.to_uppercase()
+            )
+        } else if allowed == 0 {
+            format!("есть исключённая буква {}", letter.to_uppercase())
+        } else {
+            format!(
+                "буква {} должна встречаться {} раз(а), а в слове {}",
+                letter.to_uppercase(),
+                allowed,
+                letter_count(&word_chars, letter, case)
+            )
+        });
+    }
+    Ok(())
+}
+
+/// Проверяет, что догадка состоит ровно из `length` букв.
+pub fn validate_guess(guess: &str, length: usize) -> Result<(), String> {
+    let guess_len = guess.chars().count();
+    if guess_len != length {
+        return Err(format!(
+            "догадка должна состоять из {} букв, а в '{}' их {}",
+            length, guess, guess_len
+        ));
+    }
+    Ok(())
+}
+
+/// Проверяет, что `letters` — непустой набор букв (для команд `exclude` и `include`).
+pub fn validate_letters(letters: &str) -> Result<(), String> {
+    if letters.is_empty() {
+        return Err("не указаны буквы".to_string());
+    }
+    match letters.chars().find(|c| !c.is_alphabetic()) {
+        Some(c) => Err(format!("'{}' — не буква", c)),
+        None => Ok(()),
+    }
+}
+
+/// Разбирает позицию и букву вида `3 a` или `3:a` (позиция с 1) для слова из `length` букв.
+/// Возвращает позицию с 0 и букву.
+pub fn parse_placement(input: &str, length: usize) -> Result<(usize, char), String> {
+    let mut fields = input
+        .split(|c: char| c == ':' || c.is_whitespace())
+        .filter(|field| !field.is_empty());
+    let (Some(position), Some(letter), None) = (fields.next(), fields.next(), fields.next()) else {
+        return Err(format!("ожидается «позиция буква», например «3 a», а не '{}'", input));
+    };
+    let position = parse_position(position, length)?;
+    let mut chars = letter.chars();
+    match (chars.next(), chars.next()) {
+        (Some(letter), None) if letter.is_alphabetic() => Ok((position, letter)),
+        _ => Err(format!("'{}' — не буква", letter)),
+    }
+}
+
+/// Разбирает позицию (с 1) в слове из `length` букв и возвращает её с 0.
+pub fn parse_position(input: &str, length: usize) -> Result<usize, String> {
+    let position: usize = input
+        .parse()
+        .map_err(|_| format!("позиция '{}' должна быть числом", input))?;
+    if position == 0 || position > length {
+        return Err(format!("позиция должна быть от 1 до {}, а не {}", length, position));
+    }
+    Ok(position - 1)
+}
+
+/// Переводит эмодзи из общей сетки Wordle в буквы шаблона: 🟩 → g, 🟨 → y, ⬛ и ⬜ → b.
+/// Шаблон только из цифр переводится так же: 2 → g, 1 → y, 0 → b.
+/// Остальные символы не меняются; селекторы вариантов эмодзи (U+FE0F) отбрасываются.
+pub fn normalize_pattern(input: &str) -> String {
+    if !input.is_empty() && input.chars().all(|c| matches!(c, '0'..='2')) {
+        return input
+            .chars()
+            .map(|c| match c {
+                '2' => 'g',
+                '1' => 'y',
+                _ => 'b',
+            })
+            .collect();
+    }
+    input
+        .chars()
+        .filter(|&c| c != '\u{FE0F}')
+        .map(|c| match c {
+            '🟩' => 'g',
+            '🟨' => 'y',
+            '⬛' | '⬜' => 'b',
+            other => other,
+        })
+        .collect()
+}
+
+/// Символы, которыми во вводе обозначены зелёный, жёлтый и чёрный цвета (`--pattern-map`).
+/// По умолчанию g/y/b; буквы сравниваются без учёта регистра.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PatternMap {
+    pub green: char,
+    pub yellow: char,
+    pub black: char,
+}
+
+impl Default for PatternMap {
+    fn default() -> Self {
+        PatternMap {
+            green: 'g',
+            yellow: 'y',
+            black: 'b',
+        }
+    }
+}
+
+impl std::str::FromStr for PatternMap {
+    type Err = String;
+
+    /// Разбирает запись вида `green=+,yellow=?,black=.`; не указанные цвета остаются g/y/b.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut map = PatternMap::default();
+        for item in s.split(',').map(str::trim).filter(|item| !item.is_empty()) {
+            let Some((name, symbol)) = item.split_once('=') else {
+                return Err(format!("ожидается цвет=символ, получено '{}'", item));
+            };
+            let mut chars = symbol.trim().chars();
+            let (Some(symbol), None) = (chars.next(), chars.next()) else {
+                return Err(format!("для цвета '{}' нужен ровно один символ", name.trim()));
+            };
+            match name.trim().to_ascii_lowercase().as_str() {
+                "green" => map.green = symbol,
+                "yellow" => map.yellow = symbol,
+                "black" => map.black = symbol,
+                other => return Err(format!("неизвестный цвет '{}': допустимы green, yellow, black", other)),
+            }
+        }
+        let [green, yellow, black] = [map.green, map.yellow, map.black].map(fold_letter);
+        if green == yellow || green == black || yellow == black {
+            return Err(format!(
+                "символы цветов должны различаться: green={}, yellow={}, black={}",
+                map.green, map.yellow, map.black
+            ));
+        }
+        Ok(map)
+    }
+}
+
+impl PatternMap {
+    /// Заменяет символы цветов на g/y/b; остальные символы не меняются.
+    pub fn translate(&self, input: &str) -> String {
+        input
+            .chars()
+            .map(|c| match fold_letter(c) {
+                c if c == fold_letter(self.green) => 'g',
+                c if c == fold_letter(self.yellow) => 'y',
+                c if c == fold_letter(self.black) => 'b',
+                _ => c,
+            })
+            .collect()
+    }
+}
+
+/// `normalize_pattern` с символами цветов из `map`: сначала они переводятся в g/y/b.
+pub fn normalize_pattern_with(input: &str, map: &PatternMap) -> String {
+    if *map == PatternMap::default() {
+        return normalize_pattern(input);
+    }
+    normalize_pattern(&map.translate(input))
+}
+
+/// Проверяет, что шаблон той же длины, что и догадка, состоит только из g/y/b (в любом регистре) и мог
+/// получиться на эту догадку (`check_consistency`). Шаблон должен быть уже нормализован (`normalize_pattern`): оставшиеся цифры значат смешанную запись.
+pub fn validate_pattern(guess: &str, pattern: &str) -> Result<(), String> {
+    validate_pattern_with(guess, pattern, LetterCase::Insensitive)
+}
+
+/// `validate_pattern`, где повторы буквы в догадке ищутся по правилу `case`.
+pub fn validate_pattern_with(guess: &str, pattern: &str, case: LetterCase) -> Result<(), String> {
+    let guess_len = guess.chars().count();
+    let pattern_len = pattern.chars().count();
+    if guess_len != pattern_len {
+        return Err(format!(
+            "длина шаблона ({}) не совпадает с длиной догадки ({})",
+            pattern_len, guess_len
+        ));
+    }
+    if pattern.chars().any(|c| matches!(c, '0'..='2')) {
+        return Err("в шаблоне нельзя смешивать цифры 0/1/2 с буквами g/y/b или эмодзи".to_string());
+    }
+    for (i, c) in pattern.chars().enumerate() {
+        if !(is_color(c, 'g') || is_color(c, 'y') || is_color(c, 'b')) {
+            return Err(format!(
+                "недопустимый символ '{}' в позиции {}: допустимы только g, y, b",
+                c,
+                i + 1
+            ));
+        }
+    }
+    check_consistency(&case.letters(guess), &pattern.chars().collect::<Vec<_>>(), case)
+}
+
+/// Может ли Wordle вообще выдать такой шаблон на эту догадку. Повторы буквы оцениваются слева направо: после
+/// зелёных копий жёлтыми отмечаются самые левые из оставшихся, так что чёрная копия не может стоять раньше
+/// жёлтой. Кроме того, каждой жёлтой букве нужно место в ответе: незелёная позиция, где в догадке другая буква.
+fn check_consistency(guess: &[char], pattern: &[char], case: LetterCase) -> Result<(), String> {
+    for (i, (&letter, &color)) in guess.iter().zip(pattern).enumerate() {
+        if !is_color(color, 'y') {
+            continue;
+        }
+        if let Some(j) = (0..i).find(|&j| case.same(guess[j], letter) && is_color(pattern[j], 'b')) {
+            return Err(format!(
+                "буква {} жёлтая в позиции {}, но чёрная в позиции {}: жёлтой Wordle отмечает сначала левую копию",
+                letter.to_uppercase(),
+                i + 1,
+                j + 1
+            ));
+        }
+        let yellow = (0..guess.len())
+            .filter(|&j| case.same(guess[j], letter) && is_color(pattern[j], 'y'))
+            .count();
+        let free = (0..guess.len())
+            .filter(|&j| !case.same(guess[j], letter) && !is_color(pattern[j], 'g'))
+            .count();
+        if yellow > free {
+            return Err(format!(
+                "буква {} отмечена жёлтой {} раз, но в ответе для неё только {} свободных мест",
+                letter.to_uppercase(),
+                yellow,
+                free
+            ));
+        }
+    }
+    Ok(())
+}
+
+pub fn matches_pattern(word: &str, guess: &str, pattern: &str) -> bool {
+    matches_pattern_with(word, guess, pattern, LetterCase::Insensitive)
+}
+
+/// `matches_pattern` со сравнением букв по правилу `case`. Слова не длиннее `MAX_CODED_LEN` разбираются
+/// в массивы на стеке, поэтому проверка не выделяет памяти.
+pub fn matches_pattern_with(word: &str, guess: &str, pattern: &str, case: LetterCase) -> bool {
+    let mut buffers = [['\0'; MAX_CODED_LEN]; 3];
+    let [word_buffer, guess_buffer, pattern_buffer] = &mut buffers;
+    match (
+        fill_letters(word_buffer, word, case),
+        fill_letters(guess_buffer, guess, case),
+        fill_letters(pattern_buffer, pattern, LetterCase::Sensitive),
+    ) {
+        (Some(word_chars), Some(guess_chars), Some(pattern_chars)) => {
+            matches_letters(word_chars, guess_chars, pattern_chars, case, |letter| {
+                word_chars.contains(&letter)
+            })
+        }
+        _ => {
+            let word = EncodedWord::with_case(word, case);
+            let pattern_chars: Vec<char> = pattern.chars().collect();
+            matches_encoded(&word, &EncodedWord::with_case(guess, case), &pattern_chars)
+        }
+    }
+}
+
+/// То же, что `matches_pattern`, для заранее разобранных слов; буквы сравниваются по правилу `word.case()`.
+pub fn matches_encoded(word: &EncodedWord, guess: &EncodedWord, pattern_chars: &[char]) -> bool {
+    matches_letters(word.chars(), guess.chars(), pattern_chars, word.case(), |letter| {
+        word.contains(letter)
+    })
+}
+
+/// Общая часть `matches_pattern_with` и `matches_encoded`: `contains` отвечает, есть ли буква догадки в слове.
+fn matches_letters(
+    word_chars: &[char],
+    guess_chars: &[char],
+    pattern_chars: &[char],
+    case: LetterCase,
+    contains: impl Fn(char) -> bool,
+) -> bool {
+    if word_chars.len() != guess_chars.len() || guess_chars.len() != pattern_chars.len() {
+        return false;
+    }
+
+    let revealed_missing = guess_chars
+        .iter()
+        .zip(pattern_chars)
+        .any(|(&letter, &color)| !is_color(color, 'b') && !contains(letter));
+    if revealed_missing {
+        return false;
+    }
+
+    check_greens(word_chars, guess_chars, pattern_chars, case)
+        && check_yellows(word_chars, guess_chars, pattern_chars, case)
+        && check_blacks(word_chars, guess_chars, pattern_chars, case)
+}
+
+/// Кладёт буквы слова (по правилу `case`) в начало `buffer`; `None`, если слово длиннее `MAX_CODED_LEN`.
+fn fill_letters<'a>(buffer: &'a mut [char; MAX_CODED_LEN], word: &str, case: LetterCase) -> Option<&'a [char]> {
+    let mut len = 0;
+    for letter in word.chars() {
+        *buffer.get_mut(len)? = case.fold(letter);
+        len += 1;
+    }
+    Some(&buffer[..len])
+}
+
+/// Шаблон g/y/b, который Wordle выдаст на догадку `guess` при загаданном слове `answer`.
+///
+/// Сначала зелёные занимают совпавшие позиции, затем жёлтые слева направо занимают оставшиеся
+/// вхождения буквы; лишние повторы буквы становятся чёрными.
+pub fn score_guess(answer: &str, guess: &str) -> String {
+    score_guess_with(answer, guess, LetterCase::Insensitive)
+}
+
+/// `score_guess` со сравнением букв по правилу `case`. Слова не длиннее `MAX_CODED_LEN` разбираются
+/// в массивы на стеке: память выделяется только под сам шаблон.
+pub fn score_guess_with(answer: &str, guess: &str, case: LetterCase) -> String {
+    let mut buffers = [['\0'; MAX_CODED_LEN]; 2];
+    let [answer_buffer, guess_buffer] = &mut buffers;
+    match (
+        fill_letters(answer_buffer, answer, case),
+        fill_letters(guess_buffer, guess, case),
+    ) {
+        (Some(answer_chars), Some(guess_chars)) => score_letters(answer_chars, guess_chars),
+        _ => score_letters(&case.letters(answer), &case.letters(guess)),
+    }
+}
+
+/// `score_guess` для заранее разобранных слов, без повторного разбора строк.
+pub fn score_encoded(answer: &EncodedWord, guess: &EncodedWord) -> String {
+    score_letters(answer.chars(), guess.chars())
+}
+
+/// Шаблон для уже приведённых к одному регистру букв. Занятые буквы ответа отмечаются в массиве на стеке,
+/// а для ответов длиннее `MAX_CODED_LEN` — в векторе.
+fn score_letters(answer_chars: &[char], guess_chars: &[char]) -> String {
+    let mut on_stack = [false; MAX_CODED_LEN];
+    let mut on_heap = Vec::new();
+    let used_in_answer: &mut [bool] = if answer_chars.len() <= MAX_CODED_LEN {
+        &mut on_stack[..answer_chars.len()]
+    } else {
+        on_heap.resize(answer_chars.len(), false);
+        &mut on_heap
+    };
+
+    let green = |i: usize| answer_chars.get(i) == Some(&guess_chars[i]);
+    for i in (0..guess_chars.len()).filter(|&i| green(i)) {
+        used_in_answer[i] = true;
+    }
+    let mut pattern = String::with_capacity(guess_chars.len());
+    for (i, &letter) in guess_chars.iter().enumerate() {
+        if green(i) {
+            pattern.push('g');
+        } else if let Some(j) = (0..answer_chars.len()).find(|&j| !used_in_answer[j] && answer_chars[j] == letter) {
+            used_in_answer[j] = true;
+            pattern.push('y');
+        } else {
+            pattern.push('b');
+        }
+    }
+    pattern
+}
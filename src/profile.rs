@@ -0,0 +1,68 @@
+//! Сводка времени по этапам для `--profile`: загрузка словаря, применение догадок и подсчёт подсказок.
+//! Без `--profile` замеры не делаются: `time` просто вызывает переданную функцию.
+
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static STAGES: Mutex<Vec<Stage>> = Mutex::new(Vec::new());
+
+/// Этап с числом замеров и суммарным временем.
+struct Stage {
+    name: &'static str,
+    calls: usize,
+    total: Duration,
+}
+
+/// Включает замеры (`--profile`).
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Выполняет `f` и добавляет его время к этапу `name`.
+pub fn time<T>(name: &'static str, f: impl FnOnce() -> T) -> T {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return f();
+    }
+    let start = Instant::now();
+    let result = f();
+    record(name, start.elapsed());
+    result
+}
+
+/// Добавляет уже измеренное время к этапу `name`.
+pub fn record(name: &'static str, elapsed: Duration) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    let mut stages = STAGES.lock().unwrap_or_else(|e| e.into_inner());
+    match stages.iter_mut().find(|stage| stage.name == name) {
+        Some(stage) => {
+            stage.calls += 1;
+            stage.total += elapsed;
+        }
+        None => stages.push(Stage {
+            name,
+            calls: 1,
+            total: elapsed,
+        }),
+    }
+}
+
+/// Сводка по этапам в порядке первого замера; `None`, если замеры не включены.
+pub fn summary() -> Option<String> {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return None;
+    }
+    let stages = STAGES.lock().unwrap_or_else(|e| e.into_inner());
+    let mut lines = vec!["Профиль:".to_string()];
+    lines.extend(
+        stages
+            .iter()
+            .map(|stage| format!("  {}: {} раз, {:.2?}", stage.name, stage.calls, stage.total)),
+    );
+    let total: Duration = stages.iter().map(|stage| stage.total).sum();
+    lines.push(format!("  всего: {:.2?}", total));
+    Some(lines.join("\n"))
+}
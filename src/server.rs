@@ -0,0 +1,124 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::routing::post;
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::sync::Arc;
+use wordle_solver::{
+    FilterResult, Solver, Strategy, SuggestionList, coverage_suggestions, filter_by_length, modal_length,
+    normalize_dictionary, normalize_pattern, validate_guess, validate_pattern,
+};
+
+/// Пара (догадка, шаблон) в запросе.
+#[derive(Deserialize)]
+struct GuessPattern {
+    guess: String,
+    pattern: String,
+}
+
+/// Тело запросов `/filter` и `/suggest`. Без `dictionary` используется словарь, загруженный при запуске.
+#[derive(Deserialize)]
+struct Request {
+    dictionary: Option<Vec<String>>,
+    #[serde(default)]
+    guesses: Vec<GuessPattern>,
+    top: Option<usize>,
+    strategy: Option<String>,
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: String,
+}
+
+type Response<T> = Result<Json<T>, (StatusCode, Json<ErrorBody>)>;
+
+fn bad_request(error: String) -> (StatusCode, Json<ErrorBody>) {
+    (StatusCode::BAD_REQUEST, Json(ErrorBody { error }))
+}
+
+/// Решатель, загруженный при запуске, длина его слов и предел `--max-candidates-for-suggest`.
+struct Shared {
+    solver: Solver,
+    length: usize,
+    max_candidates: usize,
+}
+
+/// HTTP-сервер (`--serve`): `POST /filter` возвращает подходящие слова, `POST /suggest` — лучшие догадки.
+/// Словарь загружается один раз; каждый запрос фильтрует свою копию решателя.
+pub fn run(solver: Solver, length: usize, port: u16, max_candidates: usize) -> io::Result<()> {
+    let shared = Arc::new(Shared {
+        solver,
+        length,
+        max_candidates,
+    });
+    let app = Router::new()
+        .route("/filter", post(filter))
+        .route("/suggest", post(suggest))
+        .with_state(shared);
+    let runtime = tokio::runtime::Runtime::new()?;
+    runtime.block_on(async {
+        let listener = tokio::net::TcpListener::bind(("127.0.0.1", port)).await?;
+        eprintln!("Сервер слушает http://{}", listener.local_addr()?);
+        axum::serve(listener, app).await
+    })
+}
+
+/// Копия решателя с применёнными догадками из запроса.
+fn solve(shared: &Shared, request: &Request) -> Result<Solver, String> {
+    let (mut solver, length) = match &request.dictionary {
+        Some(words) => {
+            let (mut words, _) = normalize_dictionary(words.iter().cloned(), Default::default());
+            let length = modal_length(&words).unwrap_or(shared.length);
+            filter_by_length(&mut words, length);
+            (Solver::new(words), length)
+        }
+        None => (shared.solver.clone(), shared.length),
+    };
+    for GuessPattern { guess, pattern } in &request.guesses {
+        let pattern = normalize_pattern(pattern);
+        validate_guess(guess, length)
+            .and_then(|_| validate_pattern(guess, &pattern))
+            .map_err(|e| format!("{}: {}", guess, e))?;
+        solver.apply(guess, &pattern);
+    }
+    Ok(solver)
+}
+
+async fn filter(State(shared): State<Arc<Shared>>, Json(request): Json<Request>) -> Response<FilterResult> {
+    let result = tokio::task::spawn_blocking(move || {
+        solve(&shared, &request).map(|solver| FilterResult::new(solver.filtered()))
+    });
+    match result.await {
+        Ok(Ok(result)) => Ok(Json(result)),
+        Ok(Err(e)) => Err(bad_request(e)),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorBody { error: e.to_string() }),
+        )),
+    }
+}
+
+async fn suggest(State(shared): State<Arc<Shared>>, Json(request): Json<Request>) -> Response<SuggestionList> {
+    let result = tokio::task::spawn_blocking(move || {
+        let strategy = match &request.strategy {
+            Some(strategy) => strategy.parse::<Strategy>()?,
+            None => Strategy::default(),
+        };
+        let solver = solve(&shared, &request)?;
+        let top = request.top.unwrap_or(crate::SUGGEST_TOP);
+        if solver.too_many_candidates(shared.max_candidates) {
+            return Ok(SuggestionList::Coverage(coverage_suggestions(solver.filtered(), top)));
+        }
+        Ok::<_, String>(SuggestionList::Scored(solver.suggestions(strategy, top)))
+    });
+    match result.await {
+        Ok(Ok(suggestions)) => Ok(Json(suggestions)),
+        Ok(Err(e)) => Err(bad_request(e)),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorBody { error: e.to_string() }),
+        )),
+    }
+}
@@ -0,0 +1,85 @@
+use crate::pattern::validate_pattern;
+use crate::solver::Solver;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs::File;
+#[cfg(not(target_arch = "wasm32"))]
+use std::io::{self, Write};
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+
+/// Версия формата файла сессии; файлы другой версии не загружаются.
+pub const SESSION_VERSION: u32 = 1;
+
+/// Сохранённое состояние решателя: путь к словарю, применённые догадки и текущий список слов.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Session {
+    pub version: u32,
+    pub dictionary: Option<String>,
+    pub history: Vec<(String, String)>,
+    pub filtered: Vec<String>,
+}
+
+impl Session {
+    /// Снимок состояния `solver`; `dictionary` — путь к словарю, как он был задан в `--dictionary`.
+    pub fn capture(solver: &Solver, dictionary: Option<&str>) -> Self {
+        Session {
+            version: SESSION_VERSION,
+            dictionary: dictionary.map(str::to_string),
+            history: solver.history().to_vec(),
+            filtered: solver.filtered().to_vec(),
+        }
+    }
+
+    /// Сбрасывает `solver` и заново применяет сохранённые догадки. Если сохранённый список отличается от
+    /// получившегося (например, после `regex`, `remove` или `add`), список приводится к сохранённому: лишние
+    /// слова убираются, а недостающие возвращаются из ответов. Возвращает число подходящих слов.
+    pub fn restore(&self, solver: &mut Solver) -> Result<usize, String> {
+        for (guess, pattern) in &self.history {
+            validate_pattern(guess, pattern).map_err(|e| format!("{}: {}", guess, e))?;
+        }
+        solver.reset();
+        for (guess, pattern) in &self.history {
+            solver.apply(guess, pattern);
+        }
+        if solver.filtered() != self.filtered.as_slice() {
+            let saved: HashSet<&str> = self.filtered.iter().map(String::as_str).collect();
+            solver.retain(|word| saved.contains(word));
+            let missing: Vec<&String> = self
+                .filtered
+                .iter()
+                .filter(|word| !solver.filtered().contains(word))
+                .collect();
+            for word in missing {
+                // Слова, которых нет в нынешних ответах (словарь поменялся), вернуть нельзя: они пропускаются.
+                let _ = solver.add(word);
+            }
+        }
+        Ok(solver.filtered().len())
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save<P: AsRef<Path>>(&self, filename: P) -> io::Result<()> {
+        let mut file = File::create(filename)?;
+        serde_json::to_writer_pretty(&mut file, self).map_err(io::Error::other)?;
+        writeln!(file)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load<P: AsRef<Path>>(filename: P) -> io::Result<Self> {
+        let file = File::open(filename)?;
+        let session: Session = serde_json::from_reader(io::BufReader::new(file))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        if session.version != SESSION_VERSION {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "неподдерживаемая версия сессии {} (ожидается {})",
+                    session.version, SESSION_VERSION
+                ),
+            ));
+        }
+        Ok(session)
+    }
+}
@@ -0,0 +1,193 @@
+use crate::pattern::{LetterCase, is_color, score_guess_with};
+use crate::solver::Solver;
+use crate::suggest::Strategy;
+
+/// Сколько догадок даёт Wordle; партии длиннее считаются нерешёнными.
+pub const MAX_GUESSES: usize = 6;
+
+/// Итоги серии партий.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SimulationStats {
+    pub games: usize,
+    pub total_guesses: usize,
+    pub max_guesses: usize,
+    /// `histogram[i]` — число партий, решённых за `i + 1` догадку; последний элемент — нерешённые.
+    pub histogram: [usize; MAX_GUESSES + 1],
+    /// Партии, в которых ответ так и не угадан (кончились подходящие слова или ходы); входят в последний
+    /// элемент `histogram`, но не в `total_guesses` и `max_guesses`.
+    pub unsolved: usize,
+}
+
+impl SimulationStats {
+    /// Записывает партию: `Some(n)` — ответ угадан за `n` догадок, `None` — не угадан вовсе.
+    pub fn record(&mut self, guesses: Option<usize>) {
+        self.games += 1;
+        let Some(guesses) = guesses else {
+            self.unsolved += 1;
+            self.histogram[MAX_GUESSES] += 1;
+            return;
+        };
+        self.total_guesses += guesses;
+        self.max_guesses = self.max_guesses.max(guesses);
+        self.histogram[guesses.clamp(1, MAX_GUESSES + 1) - 1] += 1;
+    }
+
+    pub fn failed(&self) -> usize {
+        self.histogram[MAX_GUESSES]
+    }
+
+    /// Среднее число догадок по партиям, где ответ угадан.
+    pub fn mean(&self) -> f64 {
+        let solved = self.games - self.unsolved;
+        if solved == 0 {
+            return 0.0;
+        }
+        self.total_guesses as f64 / solved as f64
+    }
+}
+
+/// Угадан ли `answer` последней из `guesses`: её шаблон целиком зелёный при сравнении букв по правилу `case`.
+pub fn is_solved(answer: &str, guesses: &[String], case: LetterCase) -> bool {
+    guesses
+        .last()
+        .is_some_and(|guess| score_guess_with(answer, guess, case).chars().all(|c| is_color(c, 'g')))
+}
+
+/// Играет партию против `answer`, каждый раз выбирая лучшую по стратегии догадку из `guesses`
+/// среди ответов `answers`. Первой догадкой играется `opener`, если он задан.
+/// Возвращает все сыгранные догадки; если ответ угадан (`is_solved`), последняя — `answer`. Партия
+/// прерывается нерешённой, когда подходящих слов не осталось, догадка стратегии ничего не убрала из списка
+/// (следующая была бы той же) или ходов сыграно больше, чем слов в словаре.
+pub fn play(
+    answer: &str,
+    guesses: &[String],
+    answers: &[String],
+    opener: Option<&str>,
+    strategy: Strategy,
+) -> Vec<String> {
+    play_on(
+        &Solver::with_answers(guesses.to_vec(), answers.to_vec()),
+        answer,
+        opener,
+        strategy,
+    )
+}
+
+/// То же, что `play`, но на копии `solver`, сброшенной к началу: догадки, ответы и сравнение букв
+/// (`LetterCase`) берутся из него.
+pub fn play_on(solver: &Solver, answer: &str, opener: Option<&str>, strategy: Strategy) -> Vec<String> {
+    let mut solver = solver.clone();
+    solver.reset();
+    let mut guesses = Vec::new();
+
+    while guesses.len() <= solver.dictionary().len() {
+        let forced = opener.is_some() && guesses.is_empty();
+        let guess = match opener {
+            Some(opener) if forced => opener.to_string(),
+            _ => match solver.best_guess_by(strategy) {
+                Some(guess) => guess,
+                None => break,
+            },
+        };
+        let pattern = score_guess_with(answer, &guess, solver.case());
+        guesses.push(guess.clone());
+        if pattern.chars().all(|c| is_color(c, 'g')) {
+            break;
+        }
+        let before = solver.filtered().len();
+        let remaining = solver.apply(&guess, &pattern);
+        if remaining == 0 || (remaining == before && !forced) {
+            break;
+        }
+    }
+    guesses
+}
+
+/// Трудность слова по числу догадок, за которое его решает стратегия с нетронутого поля.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Difficulty {
+    /// Не больше трёх догадок.
+    Easy,
+    /// Четыре догадки.
+    Medium,
+    /// Пять и больше, в том числе нерешённая партия.
+    Hard,
+}
+
+impl Difficulty {
+    pub fn from_guesses(guesses: usize) -> Self {
+        match guesses {
+            0..=3 => Difficulty::Easy,
+            4 => Difficulty::Medium,
+            _ => Difficulty::Hard,
+        }
+    }
+}
+
+impl std::fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Difficulty::Easy => "лёгкое",
+            Difficulty::Medium => "среднее",
+            Difficulty::Hard => "трудное",
+        })
+    }
+}
+
+/// Равномерная выборка из `size` слов (или весь список, если `size` не задан).
+pub fn sample(words: &[String], size: Option<usize>) -> Vec<String> {
+    match size {
+        Some(size) if size > 0 && size < words.len() => {
+            (0..size).map(|i| words[i * words.len() / size].clone()).collect()
+        }
+        _ => words.to_vec(),
+    }
+}
+
+/// Играет по партии на каждое слово из `targets`. Первая догадка одинакова для всех партий и считается один раз.
+pub fn simulate(guesses: &[String], answers: &[String], targets: &[String], strategy: Strategy) -> SimulationStats {
+    simulate_with(guesses, answers, targets, strategy, |_, _| {})
+}
+
+/// То же, что `simulate`, но после каждой партии вызывает `on_game(answer, guesses)`.
+pub fn simulate_with(
+    guesses: &[String],
+    answers: &[String],
+    targets: &[String],
+    strategy: Strategy,
+    on_game: impl FnMut(&str, &[String]),
+) -> SimulationStats {
+    let opener = Solver::with_answers(guesses.to_vec(), answers.to_vec()).best_guess_by(strategy);
+    simulate_from(guesses, answers, targets, opener.as_deref(), strategy, on_game)
+}
+
+/// То же, что `simulate_with`, но первая догадка задана заранее (`--first-guess`): `opener` играется первым в
+/// каждой партии, а дальше догадки выбирает стратегия. С `None` первую догадку тоже выбирает стратегия.
+pub fn simulate_from(
+    guesses: &[String],
+    answers: &[String],
+    targets: &[String],
+    opener: Option<&str>,
+    strategy: Strategy,
+    on_game: impl FnMut(&str, &[String]),
+) -> SimulationStats {
+    let solver = Solver::with_answers(guesses.to_vec(), answers.to_vec());
+    simulate_on(&solver, targets, opener, strategy, on_game)
+}
+
+/// То же, что `simulate_from`, но каждая партия играется на копии `solver` (см. `play_on`).
+pub fn simulate_on(
+    solver: &Solver,
+    targets: &[String],
+    opener: Option<&str>,
+    strategy: Strategy,
+    mut on_game: impl FnMut(&str, &[String]),
+) -> SimulationStats {
+    let mut stats = SimulationStats::default();
+    for target in targets {
+        let played = play_on(solver, target, opener, strategy);
+        on_game(target, &played);
+        stats.record(is_solved(target, &played, solver.case()).then_some(played.len()));
+    }
+    stats
+}
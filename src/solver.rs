@@ -0,0 +1,559 @@
+use crate::constraints::Constraints;
+use crate::index::WordIndex;
+use crate::pattern::LetterCase;
+#[cfg(feature = "parallel")]
+use crate::suggest::PARALLEL_THRESHOLD;
+use crate::suggest::{
+    BlendedGuess, ENTROPY_EPSILON, Strategy, Suggestion, entropy_with, suggest_entropy_with, suggest_minimax_with,
+    suggest_weighted_entropy_with, weighted_entropy_with, worst_case_with,
+};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::time::Instant;
+
+/// Состояние до применения очередной догадки, для отмены.
+#[derive(Clone)]
+struct Snapshot {
+    filtered_ids: Vec<usize>,
+    constraints: Constraints,
+    history_len: usize,
+}
+
+/// Словарь допустимых догадок, список возможных ответов, текущий список подходящих ответов
+/// и история применённых догадок.
+#[derive(Clone)]
+pub struct Solver {
+    dictionary: Vec<String>,
+    answers: Vec<String>,
+    index: WordIndex,
+    filtered: Vec<String>,
+    /// Номера слов из `filtered` в `answers`, в том же порядке.
+    filtered_ids: Vec<usize>,
+    history: Vec<(String, String)>,
+    constraints: Constraints,
+    undo_stack: Vec<Snapshot>,
+    /// Отменённые состояния для `redo` вместе с отменёнными парами истории.
+    redo_stack: Vec<(Snapshot, Vec<(String, String)>)>,
+    frequencies: HashMap<String, u64>,
+    /// Подсказывать только слова из текущего списка подходящих.
+    answers_only: bool,
+    /// Взвешивать кандидатов в энтропии по частотам слов.
+    weighted: bool,
+    /// Как сравниваются буквы при фильтрации.
+    case: LetterCase,
+}
+
+impl Solver {
+    /// Решатель, у которого ответом может быть любое слово словаря.
+    pub fn new(dictionary: Vec<String>) -> Self {
+        let answers = dictionary.clone();
+        Solver::with_answers(dictionary, answers)
+    }
+
+    /// Решатель с отдельным списком ответов. Ответы, которых нет в словаре догадок, добавляются в его конец.
+    pub fn with_answers(mut dictionary: Vec<String>, answers: Vec<String>) -> Self {
+        let known: HashSet<&String> = dictionary.iter().collect();
+        let missing: Vec<String> = answers.iter().filter(|word| !known.contains(word)).cloned().collect();
+        dictionary.extend(missing);
+
+        let index = WordIndex::new(&answers);
+        let filtered = answers.clone();
+        let filtered_ids = (0..answers.len()).collect();
+        Solver {
+            dictionary,
+            answers,
+            index,
+            filtered,
+            filtered_ids,
+            history: Vec::new(),
+            constraints: Constraints::new(),
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            frequencies: HashMap::new(),
+            answers_only: false,
+            weighted: false,
+            case: LetterCase::Insensitive,
+        }
+    }
+
+    /// Допустимые догадки.
+    pub fn dictionary(&self) -> &[String] {
+        &self.dictionary
+    }
+
+    /// Возможные ответы до применения догадок.
+    pub fn answers(&self) -> &[String] {
+        &self.answers
+    }
+
+    pub fn filtered(&self) -> &[String] {
+        &self.filtered
+    }
+
+    /// Больше ли подходящих слов, чем `max`, чтобы считать подсказки полностью; `0` — предела нет.
+    pub fn too_many_candidates(&self, max: usize) -> bool {
+        max > 0 && self.filtered.len() > max
+    }
+
+    /// Задаёт частоты слов: по ним сортируется `ranked` и разрешаются ничьи в подсказках.
+    pub fn set_frequencies(&mut self, frequencies: HashMap<String, u64>) {
+        self.frequencies = frequencies;
+    }
+
+    /// Подсказывать только слова, которые ещё могут оказаться ответом (`--answers-only`), а не весь словарь.
+    pub fn set_answers_only(&mut self, answers_only: bool) {
+        self.answers_only = answers_only;
+    }
+
+    pub fn answers_only(&self) -> bool {
+        self.answers_only
+    }
+
+    /// Считать энтропию так, будто кандидат загадан с вероятностью, пропорциональной его частоте (`--weighted`).
+    /// Без таблицы частот ничего не меняет.
+    pub fn set_weighted(&mut self, weighted: bool) {
+        self.weighted = weighted;
+    }
+
+    pub fn weighted(&self) -> bool {
+        self.weighted
+    }
+
+    /// Задаёт, как сравниваются буквы при фильтрации (`--case-sensitive` — `LetterCase::Sensitive`).
+    /// Индекс слов перестраивается, а фильтр сбрасывается, как после `reset`.
+    pub fn set_case(&mut self, case: LetterCase) {
+        self.case = case;
+        self.index = WordIndex::with_case(&self.answers, case);
+        self.reset();
+    }
+
+    pub fn case(&self) -> LetterCase {
+        self.case
+    }
+
+    /// Веса кандидатов для взвешенной энтропии: частота + 1, чтобы слова без частоты не выпадали совсем.
+    /// `None`, если взвешивание выключено или таблицы частот нет.
+    fn weights(&self) -> Option<Vec<f64>> {
+        if !self.weighted || self.frequencies.is_empty() {
+            return None;
+        }
+        Some(
+            self.filtered
+                .iter()
+                .map(|word| self.frequency(word) as f64 + 1.0)
+                .collect(),
+        )
+    }
+
+    /// Энтропия догадки на текущем списке, с учётом `set_weighted`.
+    pub fn guess_entropy(&self, guess: &str) -> f64 {
+        match self.weights() {
+            Some(weights) => weighted_entropy_with(guess, &self.filtered, &weights, self.case),
+            None => entropy_with(guess, &self.filtered, self.case),
+        }
+    }
+
+    /// Слова, среди которых выбираются подсказки: весь словарь или, при `answers_only`, текущий список.
+    fn guess_pool(&self) -> &[String] {
+        if self.answers_only {
+            &self.filtered
+        } else {
+            &self.dictionary
+        }
+    }
+
+    /// Частота слова (0, если слова нет в таблице частот).
+    pub fn frequency(&self, word: &str) -> u64 {
+        self.frequencies.get(word).copied().unwrap_or(0)
+    }
+
+    /// Сколько подходящих слов частые: их частота не ниже медианы таблицы частот. Без таблицы частот — `None`.
+    pub fn common_count(&self) -> Option<usize> {
+        let mut counts: Vec<u64> = self.frequencies.values().copied().collect();
+        if counts.is_empty() {
+            return None;
+        }
+        counts.sort_unstable();
+        let median = counts[counts.len() / 2];
+        Some(
+            self.filtered
+                .iter()
+                .filter(|word| self.frequency(word) >= median)
+                .count(),
+        )
+    }
+
+    /// Подходящие слова по убыванию частоты; без таблицы частот — в порядке словаря.
+    pub fn ranked(&self) -> Vec<&String> {
+        let mut ranked: Vec<&String> = self.filtered.iter().collect();
+        ranked.sort_by_key(|word| std::cmp::Reverse(self.frequency(word)));
+        ranked
+    }
+
+    /// Применённые пары (догадка, шаблон) в порядке ввода.
+    pub fn history(&self) -> &[(String, String)] {
+        &self.history
+    }
+
+    /// Ограничения, накопленные по всем применённым догадкам.
+    pub fn constraints(&self) -> &Constraints {
+        &self.constraints
+    }
+
+    /// Оставляет только слова, совместимые с догадкой и шаблоном. Возвращает число оставшихся слов.
+    pub fn apply(&mut self, guess: &str, pattern: &str) -> usize {
+        self.save_snapshot();
+        self.constraints.add(guess, pattern);
+        self.filter();
+        self.history.push((guess.to_string(), pattern.to_string()));
+        self.filtered.len()
+    }
+
+    /// Оставляет только слова, для которых `keep` возвращает `true` (дополнительный фильтр поверх подсказок,
+    /// в историю не попадает, но отменяется через `undo`). Возвращает число оставшихся слов.
+    pub fn retain(&mut self, keep: impl Fn(&str) -> bool) -> usize {
+        self.save_snapshot();
+        let answers = &self.answers;
+        self.filtered_ids.retain(|&id| keep(&answers[id]));
+        self.sync_filtered();
+        self.filtered.len()
+    }
+
+    /// Исключает буквы `letters` из слова, как если бы они были чёрными. В историю не попадает,
+    /// но отменяется через `undo`. Возвращает число оставшихся слов.
+    pub fn exclude(&mut self, letters: &str) -> usize {
+        self.save_snapshot();
+        letters.chars().for_each(|letter| self.constraints.exclude(letter));
+        self.filter();
+        self.filtered.len()
+    }
+
+    /// Требует, чтобы каждая из букв `letters` была в слове. В историю не попадает,
+    /// но отменяется через `undo`. Возвращает число оставшихся слов.
+    pub fn include(&mut self, letters: &str) -> usize {
+        self.save_snapshot();
+        letters.chars().for_each(|letter| self.constraints.include(letter));
+        self.filter();
+        self.filtered.len()
+    }
+
+    /// Ставит букву зелёной на позицию `position` (с 0) в слове из `length` букв, без полной догадки.
+    /// В историю не попадает, но отменяется через `undo`. Возвращает число оставшихся слов.
+    pub fn set_green(&mut self, position: usize, letter: char, length: usize) -> usize {
+        self.save_snapshot();
+        self.constraints.set_green(position, letter, length);
+        self.filter();
+        self.filtered.len()
+    }
+
+    /// Отмечает букву жёлтой на позиции `position` (с 0): она есть в слове, но не на этой позиции.
+    /// В историю не попадает, но отменяется через `undo`. Возвращает число оставшихся слов.
+    pub fn add_yellow(&mut self, position: usize, letter: char, length: usize) -> usize {
+        self.save_snapshot();
+        self.constraints.add_yellow(position, letter, length);
+        self.filter();
+        self.filtered.len()
+    }
+
+    /// Убирает слово из текущего списка, не трогая словарь. Отменяется через `undo`.
+    /// Возвращает число оставшихся слов или ошибку, если слова в списке нет.
+    pub fn remove(&mut self, word: &str) -> Result<usize, String> {
+        let position = self
+            .filtered
+            .iter()
+            .position(|candidate| self.case.letters(candidate) == self.case.letters(word))
+            .ok_or_else(|| format!("слова '{}' нет среди подходящих", word))?;
+        self.save_snapshot();
+        self.filtered_ids.remove(position);
+        self.filtered.remove(position);
+        Ok(self.filtered.len())
+    }
+
+    /// Возвращает в текущий список слово из исходного списка ответов, даже если оно не подходит
+    /// под подсказки; следующая догадка снова проверит его. Отменяется через `undo`.
+    /// Возвращает число подходящих слов или ошибку, если слова нет в ответах или оно уже в списке.
+    pub fn add(&mut self, word: &str) -> Result<usize, String> {
+        let id = self
+            .answers
+            .iter()
+            .position(|answer| self.case.letters(answer) == self.case.letters(word))
+            .ok_or_else(|| format!("слова '{}' нет в словаре", word))?;
+        let position = match self.filtered_ids.binary_search(&id) {
+            Ok(_) => return Err(format!("слово '{}' уже среди подходящих", word)),
+            Err(position) => position,
+        };
+        self.save_snapshot();
+        self.filtered_ids.insert(position, id);
+        self.filtered.insert(position, self.answers[id].clone());
+        Ok(self.filtered.len())
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            filtered_ids: self.filtered_ids.clone(),
+            constraints: self.constraints.clone(),
+            history_len: self.history.len(),
+        }
+    }
+
+    /// Запоминает состояние для `undo`. Новое действие делает отменённые недоступными для `redo`.
+    fn save_snapshot(&mut self) {
+        self.undo_stack.push(self.snapshot());
+        self.redo_stack.clear();
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.filtered_ids = snapshot.filtered_ids;
+        self.sync_filtered();
+        self.constraints = snapshot.constraints;
+    }
+
+    /// Фильтрует текущий список по ограничениям. С отладочным логом (`RUST_LOG=debug` или `--verbose`)
+    /// сообщает, сколько слов отсекло каждое правило и сколько занял сам фильтр.
+    fn filter(&mut self) {
+        if !log::log_enabled!(log::Level::Debug) {
+            self.filter_ids();
+            return;
+        }
+        let stages = self.stage_summary();
+        let (before, started) = (self.filtered_ids.len(), Instant::now());
+        self.filter_ids();
+        log::debug!(
+            "фильтр: {} → {} слов за {:.2?}; {}",
+            before,
+            self.filtered_ids.len(),
+            started.elapsed(),
+            stages
+        );
+    }
+
+    /// Сколько слов текущего списка отсекает обратный индекс и сколько — каждое правило (зелёные, жёлтые,
+    /// чёрные буквы) по накопленным ограничениям. Считается отдельным проходом, только для отладочного лога,
+    /// чтобы не замедлять сам фильтр.
+    fn stage_summary(&self) -> String {
+        let allowed = self.index.narrow(&self.constraints);
+        let mut by_index = 0;
+        let mut by_stage: BTreeMap<&str, usize> = BTreeMap::new();
+        for &id in &self.filtered_ids {
+            if !allowed.contains(id) {
+                by_index += 1;
+            }
+            if let Some(stage) = self.constraints.failed_stage(self.index.word(id)) {
+                *by_stage.entry(stage).or_default() += 1;
+            }
+        }
+        let count = |stage| by_stage.get(stage).copied().unwrap_or(0);
+        format!(
+            "индекс отсёк {}, зелёные — {}, жёлтые — {}, чёрные — {}",
+            by_index,
+            count("green"),
+            count("yellow"),
+            count("black")
+        )
+    }
+
+    /// Оставляет слова, подходящие под накопленные ограничения: обратный индекс сразу отсекает большую часть,
+    /// точная проверка нужна только остальным.
+    fn filter_ids(&mut self) {
+        let allowed = self.index.narrow(&self.constraints);
+        let (index, constraints) = (&self.index, &self.constraints);
+        let matches = |id: &usize| allowed.contains(*id) && constraints.matches_encoded(index.word(*id));
+
+        #[cfg(feature = "parallel")]
+        if self.filtered_ids.len() > PARALLEL_THRESHOLD {
+            use rayon::prelude::*;
+            self.filtered_ids = std::mem::take(&mut self.filtered_ids)
+                .into_par_iter()
+                .filter(matches)
+                .collect();
+            self.sync_filtered();
+            return;
+        }
+
+        self.filtered_ids.retain(matches);
+        self.sync_filtered();
+    }
+
+    fn sync_filtered(&mut self) {
+        self.filtered = self.filtered_ids.iter().map(|&id| self.answers[id].clone()).collect();
+    }
+
+    /// Для каждой применённой догадки — решатель в состоянии прямо перед ней (с фильтрами, наложенными
+    /// до неё) и число подходящих слов сразу после неё. Восстанавливается по снимкам для `undo`,
+    /// поэтому учитывает `exclude`, `include`, `set_green`, `remove` и прочие действия вне истории.
+    pub fn turns(&self) -> Vec<(Solver, usize)> {
+        (0..self.history.len())
+            .filter_map(|turn| {
+                let before = self
+                    .undo_stack
+                    .iter()
+                    .rev()
+                    .find(|snapshot| snapshot.history_len == turn)?;
+                let remaining = self
+                    .undo_stack
+                    .iter()
+                    .find(|snapshot| snapshot.history_len == turn + 1)
+                    .map_or(self.filtered.len(), |snapshot| snapshot.filtered_ids.len());
+                let mut state = self.clone();
+                state.history.truncate(turn);
+                state.undo_stack.clear();
+                state.redo_stack.clear();
+                state.restore(before.clone());
+                Some((state, remaining))
+            })
+            .collect()
+    }
+
+    /// Отменяет последнюю применённую догадку или фильтр. Возвращает `false`, если отменять нечего.
+    pub fn undo(&mut self) -> bool {
+        let Some(snapshot) = self.undo_stack.pop() else {
+            return false;
+        };
+        let current = self.snapshot();
+        let undone = self.history.split_off(snapshot.history_len);
+        self.redo_stack.push((current, undone));
+        self.restore(snapshot);
+        true
+    }
+
+    /// Возвращает последнее отменённое через `undo` действие. Возвращает `false`, если возвращать нечего:
+    /// ничего не отменялось или после отмены было новое действие.
+    pub fn redo(&mut self) -> bool {
+        let Some((snapshot, undone)) = self.redo_stack.pop() else {
+            return false;
+        };
+        self.undo_stack.push(self.snapshot());
+        self.history.extend(undone);
+        self.restore(snapshot);
+        true
+    }
+
+    /// Предлагает следующую догадку: все слова словаря (или, с `answers_only`, текущего списка),
+    /// отсортированные по энтропии на текущем списке (с `weighted` — по взвешенной частотами).
+    /// При равной энтропии выше стоят более частые слова.
+    pub fn suggest(&self) -> Vec<(String, f64)> {
+        let mut suggestions = match self.weights() {
+            Some(weights) => suggest_weighted_entropy_with(&self.filtered, &weights, self.guess_pool(), self.case),
+            None => suggest_entropy_with(&self.filtered, self.guess_pool(), self.case),
+        };
+        if !self.frequencies.is_empty() {
+            suggestions.sort_by(|a, b| {
+                b.1.total_cmp(&a.1)
+                    .then(self.frequency(&b.0).cmp(&self.frequency(&a.0)))
+            });
+        }
+        suggestions
+    }
+
+    /// Все слова словаря (или текущего списка) по возрастанию наибольшей группы на текущем списке; при равенстве выше более частые.
+    pub fn suggest_minimax(&self) -> Vec<(String, usize)> {
+        let mut suggestions = suggest_minimax_with(&self.filtered, self.guess_pool(), self.case);
+        if !self.frequencies.is_empty() {
+            suggestions.sort_by(|a, b| a.1.cmp(&b.1).then(self.frequency(&b.0).cmp(&self.frequency(&a.0))));
+        }
+        suggestions
+    }
+
+    /// `top` лучших догадок по стратегии со всеми метриками (вторая метрика считается только для них).
+    pub fn suggestions(&self, strategy: Strategy, top: usize) -> Vec<Suggestion> {
+        let describe = |word: &String, entropy: f64, worst_case: usize| Suggestion {
+            word: word.clone(),
+            entropy,
+            worst_case,
+            candidate: self.filtered.contains(word),
+        };
+        match strategy {
+            Strategy::Entropy => self
+                .suggest()
+                .iter()
+                .take(top)
+                .map(|(word, bits)| describe(word, *bits, worst_case_with(word, &self.filtered, self.case)))
+                .collect(),
+            Strategy::Minimax => self
+                .suggest_minimax()
+                .iter()
+                .take(top)
+                .map(|(word, worst)| describe(word, self.guess_entropy(word), *worst))
+                .collect(),
+        }
+    }
+
+    /// Из равноценных догадок выбирает слово из текущего списка (у него есть шанс оказаться ответом),
+    /// затем — более частое; при полном равенстве — первое.
+    fn pick_tied<'a>(&self, tied: impl Iterator<Item = &'a String>) -> Option<&'a String> {
+        let mut best: Option<(&String, (bool, u64))> = None;
+        for word in tied {
+            let rank = (self.filtered.contains(word), self.frequency(word));
+            if best.as_ref().is_none_or(|(_, best_rank)| rank > *best_rank) {
+                best = Some((word, rank));
+            }
+        }
+        best.map(|(word, _)| word)
+    }
+
+    /// Лучшая следующая догадка по энтропии. При равенстве предпочитается слово из текущего списка
+    /// (у него есть шанс оказаться ответом), затем — более частое слово.
+    pub fn best_guess(&self) -> Option<(String, f64)> {
+        let suggestions = self.suggest();
+        let top = suggestions.first()?.1;
+        let tied = suggestions.iter().take_while(|(_, bits)| bits + ENTROPY_EPSILON >= top);
+        let word = self.pick_tied(tied.map(|(word, _)| word))?;
+        Some((word.clone(), top))
+    }
+
+    /// Лучшая догадка по смешанной оценке `alpha * энтропия + (1 - alpha) * p_answer` (см. `BlendedGuess`): при
+    /// `alpha = 1` это `best_guess`, при `alpha = 0` — самое вероятное из подходящих слов. Равенство — как в
+    /// `best_guess`.
+    pub fn best_blended(&self, alpha: f64) -> Option<BlendedGuess> {
+        let candidates: HashSet<&String> = self.filtered.iter().collect();
+        let top_frequency = self.filtered.iter().map(|word| self.frequency(word)).max().unwrap_or(0);
+        let p_answer = |word: &String| match candidates.contains(word) {
+            false => 0.0,
+            true if self.frequencies.is_empty() => 1.0,
+            true => (self.frequency(word) + 1) as f64 / (top_frequency + 1) as f64,
+        };
+        let scored: Vec<BlendedGuess> = self
+            .suggest()
+            .into_iter()
+            .map(|(word, entropy)| {
+                let p_answer = p_answer(&word);
+                BlendedGuess {
+                    score: alpha * entropy + (1.0 - alpha) * p_answer,
+                    word,
+                    entropy,
+                    p_answer,
+                }
+            })
+            .collect();
+        let top = scored.iter().map(|guess| guess.score).max_by(f64::total_cmp)?;
+        let tied = scored.iter().filter(|guess| guess.score + ENTROPY_EPSILON >= top);
+        let word = self.pick_tied(tied.map(|guess| &guess.word))?;
+        scored.iter().find(|guess| &guess.word == word).cloned()
+    }
+
+    /// Лучшая догадка по наименьшей наибольшей группе, с теми же правилами для равенства, что и `best_guess`.
+    pub fn best_guess_minimax(&self) -> Option<(String, usize)> {
+        let suggestions = self.suggest_minimax();
+        let top = suggestions.first()?.1;
+        let tied = suggestions.iter().take_while(|(_, worst)| *worst == top);
+        let word = self.pick_tied(tied.map(|(word, _)| word))?;
+        Some((word.clone(), top))
+    }
+
+    /// Лучшая догадка по выбранной стратегии.
+    pub fn best_guess_by(&self, strategy: Strategy) -> Option<String> {
+        match strategy {
+            Strategy::Entropy => self.best_guess().map(|(word, _)| word),
+            Strategy::Minimax => self.best_guess_minimax().map(|(word, _)| word),
+        }
+    }
+
+    /// Сбрасывает фильтр к исходному списку ответов.
+    pub fn reset(&mut self) {
+        self.filtered = self.answers.clone();
+        self.filtered_ids = (0..self.answers.len()).collect();
+        self.history.clear();
+        self.constraints = Constraints::with_case(self.case);
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+    }
+}
@@ -0,0 +1,302 @@
+use crate::analysis::rank_probes;
+use crate::encoded::{EncodedWord, MAX_CODED_LEN, pattern_code};
+use crate::pattern::{LetterCase, score_encoded, score_guess_with};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Слова, разобранные для сравнения по правилу `case`.
+fn encode(words: &[String], case: LetterCase) -> Vec<EncodedWord> {
+    words.iter().map(|word| EncodedWord::with_case(word, case)).collect()
+}
+
+/// Энтропия Шеннона (в битах) распределения шаблонов, которые `guess` даёт на `candidates`.
+pub fn entropy(guess: &str, candidates: &[String]) -> f64 {
+    entropy_with(guess, candidates, LetterCase::Insensitive)
+}
+
+/// `entropy` со сравнением букв по правилу `case`. Эта и остальные функции `*_with` ниже нужны решателю
+/// с `--case-sensitive`: без них `Polish` и `polish` дали бы одинаковые шаблоны.
+pub fn entropy_with(guess: &str, candidates: &[String], case: LetterCase) -> f64 {
+    entropy_encoded(&EncodedWord::with_case(guess, case), &encode(candidates, case))
+}
+
+/// Разбивает `candidates` на группы по шаблону, который даст `guess`: шаблон → число слов.
+pub fn partition(guess: &str, candidates: &[String]) -> HashMap<String, usize> {
+    partition_with(guess, candidates, LetterCase::Insensitive)
+}
+
+/// `partition` со сравнением букв по правилу `case`.
+pub fn partition_with(guess: &str, candidates: &[String], case: LetterCase) -> HashMap<String, usize> {
+    let mut buckets = HashMap::new();
+    for answer in candidates {
+        *buckets.entry(score_guess_with(answer, guess, case)).or_default() += 1;
+    }
+    buckets
+}
+
+/// Сколько слов из `candidates` в среднем останется после догадки `guess`, если ответ равновероятно любой из
+/// них: сумма квадратов размеров групп `partition`, делённая на число слов. Чем меньше, тем лучше.
+pub fn expected_remaining(guess: &str, candidates: &[String]) -> f64 {
+    expected_remaining_with(guess, candidates, LetterCase::Insensitive)
+}
+
+/// `expected_remaining` со сравнением букв по правилу `case`.
+pub fn expected_remaining_with(guess: &str, candidates: &[String], case: LetterCase) -> f64 {
+    if candidates.is_empty() {
+        return 0.0;
+    }
+    let squares: usize = partition_with(guess, candidates, case)
+        .values()
+        .map(|size| size * size)
+        .sum();
+    squares as f64 / candidates.len() as f64
+}
+
+/// Размеры групп, на которые `guess` разбивает `candidates` по шаблону, по возрастанию.
+fn bucket_sizes(guess: &EncodedWord, candidates: &[EncodedWord]) -> Vec<usize> {
+    let mut counts: Vec<usize> = if guess.len() <= MAX_CODED_LEN {
+        let mut codes: Vec<u64> = candidates.iter().map(|answer| pattern_code(answer, guess)).collect();
+        codes.sort_unstable();
+        codes.chunk_by(|a, b| a == b).map(|run| run.len()).collect()
+    } else {
+        let mut buckets: HashMap<String, usize> = HashMap::new();
+        for answer in candidates {
+            *buckets.entry(score_encoded(answer, guess)).or_default() += 1;
+        }
+        buckets.into_values().collect()
+    };
+    counts.sort_unstable();
+    counts
+}
+
+pub(crate) fn entropy_encoded(guess: &EncodedWord, candidates: &[EncodedWord]) -> f64 {
+    let total = candidates.len() as f64;
+    bucket_sizes(guess, candidates)
+        .into_iter()
+        .map(|count| {
+            let p = count as f64 / total;
+            p * (1.0 / p).log2()
+        })
+        .fold(0.0, |sum, term| sum + term)
+}
+
+/// Энтропия распределения шаблонов, когда кандидат `candidates[i]` загадан с вероятностью, пропорциональной
+/// `weights[i]`. При равных весах совпадает с `entropy`.
+pub fn weighted_entropy(guess: &str, candidates: &[String], weights: &[f64]) -> f64 {
+    weighted_entropy_with(guess, candidates, weights, LetterCase::Insensitive)
+}
+
+/// `weighted_entropy` со сравнением букв по правилу `case`.
+pub fn weighted_entropy_with(guess: &str, candidates: &[String], weights: &[f64], case: LetterCase) -> f64 {
+    weighted_entropy_encoded(&EncodedWord::with_case(guess, case), &encode(candidates, case), weights)
+}
+
+/// Суммы весов кандидатов в каждой группе, на которые `guess` разбивает `candidates` по шаблону.
+fn bucket_weights(guess: &EncodedWord, candidates: &[EncodedWord], weights: &[f64]) -> Vec<f64> {
+    if guess.len() <= MAX_CODED_LEN {
+        let mut codes: Vec<(u64, f64)> = candidates
+            .iter()
+            .zip(weights)
+            .map(|(answer, &weight)| (pattern_code(answer, guess), weight))
+            .collect();
+        codes.sort_unstable_by_key(|&(code, _)| code);
+        codes
+            .chunk_by(|a, b| a.0 == b.0)
+            .map(|run| run.iter().map(|&(_, weight)| weight).sum())
+            .collect()
+    } else {
+        let mut buckets: HashMap<String, f64> = HashMap::new();
+        for (answer, &weight) in candidates.iter().zip(weights) {
+            *buckets.entry(score_encoded(answer, guess)).or_default() += weight;
+        }
+        buckets.into_values().collect()
+    }
+}
+
+fn weighted_entropy_encoded(guess: &EncodedWord, candidates: &[EncodedWord], weights: &[f64]) -> f64 {
+    let total: f64 = weights.iter().sum();
+    if total <= 0.0 {
+        return 0.0;
+    }
+    bucket_weights(guess, candidates, weights)
+        .into_iter()
+        .filter(|&weight| weight > 0.0)
+        .map(|weight| {
+            let p = weight / total;
+            p * (1.0 / p).log2()
+        })
+        .fold(0.0, |sum, term| sum + term)
+}
+
+/// Допуск, в пределах которого энтропии двух догадок считаются равными.
+pub const ENTROPY_EPSILON: f64 = 1e-9;
+
+/// С фичей `parallel` оценка догадок и фильтрация распараллеливаются, только если кандидатов больше этого
+/// числа: на маленьких списках накладные расходы на потоки не окупаются.
+pub const PARALLEL_THRESHOLD: usize = 500;
+
+/// Оценивает каждую догадку функцией `score`; с фичей `parallel` — параллельно, если кандидатов больше
+/// `PARALLEL_THRESHOLD`. Догадки разбираются по правилу `case`.
+fn score_guesses<T: Send>(
+    guesses: &[String],
+    candidates: usize,
+    case: LetterCase,
+    score: impl Fn(&EncodedWord) -> T + Sync,
+) -> Vec<(String, T)> {
+    let score = |guess: &String| (guess.clone(), score(&EncodedWord::with_case(guess, case)));
+
+    #[cfg(feature = "parallel")]
+    if candidates > PARALLEL_THRESHOLD {
+        use rayon::prelude::*;
+        return guesses.par_iter().map(score).collect();
+    }
+    #[cfg(not(feature = "parallel"))]
+    let _ = candidates;
+
+    guesses.iter().map(score).collect()
+}
+
+/// Оценивает каждую догадку из `guesses` по энтропии на `candidates` и сортирует по убыванию.
+pub fn suggest_entropy(candidates: &[String], guesses: &[String]) -> Vec<(String, f64)> {
+    suggest_entropy_with(candidates, guesses, LetterCase::Insensitive)
+}
+
+/// `suggest_entropy` со сравнением букв по правилу `case`.
+pub fn suggest_entropy_with(candidates: &[String], guesses: &[String], case: LetterCase) -> Vec<(String, f64)> {
+    let encoded = encode(candidates, case);
+    let mut scored = score_guesses(guesses, candidates.len(), case, |guess| {
+        entropy_encoded(guess, &encoded)
+    });
+    scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+    scored
+}
+
+/// То же, что `suggest_entropy`, но кандидаты взвешены `weights` (см. `weighted_entropy`).
+pub fn suggest_weighted_entropy(candidates: &[String], weights: &[f64], guesses: &[String]) -> Vec<(String, f64)> {
+    suggest_weighted_entropy_with(candidates, weights, guesses, LetterCase::Insensitive)
+}
+
+/// `suggest_weighted_entropy` со сравнением букв по правилу `case`.
+pub fn suggest_weighted_entropy_with(
+    candidates: &[String],
+    weights: &[f64],
+    guesses: &[String],
+    case: LetterCase,
+) -> Vec<(String, f64)> {
+    let encoded = encode(candidates, case);
+    let mut scored = score_guesses(guesses, candidates.len(), case, |guess| {
+        weighted_entropy_encoded(guess, &encoded, weights)
+    });
+    scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+    scored
+}
+
+/// Размер наибольшей группы, на которые `guess` разбивает `candidates` (худший случай).
+pub fn worst_case(guess: &str, candidates: &[String]) -> usize {
+    worst_case_with(guess, candidates, LetterCase::Insensitive)
+}
+
+/// `worst_case` со сравнением букв по правилу `case`.
+pub fn worst_case_with(guess: &str, candidates: &[String], case: LetterCase) -> usize {
+    worst_case_encoded(&EncodedWord::with_case(guess, case), &encode(candidates, case))
+}
+
+fn worst_case_encoded(guess: &EncodedWord, candidates: &[EncodedWord]) -> usize {
+    bucket_sizes(guess, candidates).last().copied().unwrap_or(0)
+}
+
+/// Оценивает каждую догадку из `guesses` по размеру наибольшей группы на `candidates` и сортирует
+/// по возрастанию: первой идёт догадка с наименьшим худшим случаем.
+pub fn suggest_minimax(candidates: &[String], guesses: &[String]) -> Vec<(String, usize)> {
+    suggest_minimax_with(candidates, guesses, LetterCase::Insensitive)
+}
+
+/// `suggest_minimax` со сравнением букв по правилу `case`.
+pub fn suggest_minimax_with(candidates: &[String], guesses: &[String], case: LetterCase) -> Vec<(String, usize)> {
+    let encoded = encode(candidates, case);
+    let mut scored = score_guesses(guesses, candidates.len(), case, |guess| {
+        worst_case_encoded(guess, &encoded)
+    });
+    scored.sort_by_key(|(_, worst)| *worst);
+    scored
+}
+
+/// Догадка со всеми метриками сразу: энтропия, худший случай и может ли она сама оказаться ответом.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Suggestion {
+    pub word: String,
+    pub entropy: f64,
+    pub worst_case: usize,
+    pub candidate: bool,
+}
+
+/// Догадка, выбранная по охвату букв (`coverage_score`), когда подходящих слов слишком много для подсчёта
+/// энтропии (`--max-candidates-for-suggest`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CoverageSuggestion {
+    pub word: String,
+    pub coverage: u32,
+}
+
+/// `top` слов из `words` с наибольшим охватом букв (см. `rank_probes`).
+pub fn coverage_suggestions(words: &[String], top: usize) -> Vec<CoverageSuggestion> {
+    rank_probes(words)
+        .into_iter()
+        .take(top)
+        .map(|(word, coverage)| CoverageSuggestion {
+            word: word.clone(),
+            coverage,
+        })
+        .collect()
+}
+
+/// Подсказки для машинного вывода (`--format json`, `POST /suggest`): по энтропии или по охвату букв.
+/// В JSON — просто массив тех или других.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum SuggestionList {
+    Scored(Vec<Suggestion>),
+    Coverage(Vec<CoverageSuggestion>),
+}
+
+/// Догадка команды `best`: смешанная оценка `alpha * entropy + (1 - alpha) * p_answer` и обе её части.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlendedGuess {
+    pub word: String,
+    pub entropy: f64,
+    /// Насколько вероятно, что слово — ответ: 0 для слов не из текущего списка, иначе 1 (с частотами — доля
+    /// от частоты самого частого подходящего слова).
+    pub p_answer: f64,
+    pub score: f64,
+}
+
+/// Стратегия выбора следующей догадки.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Strategy {
+    /// Наибольшая энтропия: максимум информации в среднем.
+    #[default]
+    Entropy,
+    /// Наименьшая наибольшая группа: гарантированный прогресс в худшем случае.
+    Minimax,
+}
+
+impl std::str::FromStr for Strategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "entropy" => Ok(Strategy::Entropy),
+            "minimax" => Ok(Strategy::Minimax),
+            _ => Err(format!("неизвестная стратегия '{}': допустимы entropy и minimax", s)),
+        }
+    }
+}
+
+impl std::fmt::Display for Strategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Strategy::Entropy => "entropy",
+            Strategy::Minimax => "minimax",
+        })
+    }
+}
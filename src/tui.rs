@@ -0,0 +1,190 @@
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, List, ListState, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+use std::io;
+use wordle_solver::{Solver, format_elimination, violates_hard_mode};
+
+const HELP: &str = "буквы — догадка, ←/→ — ячейка, пробел/↑/↓ — цвет, Enter — применить, Backspace — стереть\n\
+    PgUp/PgDn — прокрутка, Ctrl-Z — отменить, Ctrl-Y — вернуть, Ctrl-N — заново, Esc — выход";
+
+/// Вводимая догадка: буквы, их цвета (g/y/b) и выбранная ячейка.
+struct Editor {
+    letters: Vec<char>,
+    colors: Vec<char>,
+    cursor: usize,
+    message: String,
+    list: ListState,
+}
+
+impl Editor {
+    fn new() -> Self {
+        Editor {
+            letters: Vec::new(),
+            colors: Vec::new(),
+            cursor: 0,
+            message: String::new(),
+            list: ListState::default().with_selected(Some(0)),
+        }
+    }
+
+    fn clear(&mut self) {
+        self.letters.clear();
+        self.colors.clear();
+        self.cursor = 0;
+        self.list.select(Some(0));
+    }
+
+    /// Меняет цвет выбранной ячейки по кругу b → y → g (или обратно).
+    fn cycle(&mut self, forward: bool) {
+        if let Some(color) = self.colors.get_mut(self.cursor) {
+            *color = match (*color, forward) {
+                ('b', true) | ('g', false) => 'y',
+                ('y', true) | ('b', false) => 'g',
+                _ => 'b',
+            };
+        }
+    }
+}
+
+/// Полноэкранный режим (`--tui`): сетка догадок с цветами, число подходящих слов и их прокручиваемый список.
+/// При выходе терминал возвращается в исходное состояние.
+pub fn run(solver: &mut Solver, length: usize, hard: bool) -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    let result = event_loop(&mut terminal, solver, length, hard);
+    ratatui::restore();
+    result
+}
+
+fn event_loop(terminal: &mut DefaultTerminal, solver: &mut Solver, length: usize, hard: bool) -> io::Result<()> {
+    let mut editor = Editor::new();
+    loop {
+        terminal.draw(|frame| draw(frame, solver, &mut editor, length))?;
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        if !handle_key(key, solver, &mut editor, length, hard) {
+            return Ok(());
+        }
+    }
+}
+
+/// Обрабатывает нажатие; `false` — выйти.
+fn handle_key(key: KeyEvent, solver: &mut Solver, editor: &mut Editor, length: usize, hard: bool) -> bool {
+    let control = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Esc => return false,
+        KeyCode::Char('c') if control => return false,
+        KeyCode::Char('z') if control => {
+            editor.message = if solver.undo() {
+                "Последняя догадка отменена.".to_string()
+            } else {
+                "Отменять нечего.".to_string()
+            };
+            editor.list.select(Some(0));
+        }
+        KeyCode::Char('y') if control => {
+            editor.message = if solver.redo() {
+                "Отменённая догадка возвращена.".to_string()
+            } else {
+                "Возвращать нечего.".to_string()
+            };
+            editor.list.select(Some(0));
+        }
+        KeyCode::Char('n') if control => {
+            solver.reset();
+            editor.clear();
+            editor.message = "Фильтр сброшен.".to_string();
+        }
+        KeyCode::Char(' ') | KeyCode::Up => editor.cycle(true),
+        KeyCode::Down => editor.cycle(false),
+        KeyCode::Char(letter) if letter.is_alphabetic() && editor.letters.len() < length => {
+            editor.letters.push(letter.to_lowercase().next().unwrap_or(letter));
+            editor.colors.push('b');
+            editor.cursor = editor.letters.len() - 1;
+        }
+        KeyCode::Backspace => {
+            editor.letters.pop();
+            editor.colors.pop();
+            editor.cursor = editor.letters.len().saturating_sub(1);
+        }
+        KeyCode::Left => editor.cursor = editor.cursor.saturating_sub(1),
+        KeyCode::Right => editor.cursor = (editor.cursor + 1).min(editor.letters.len().saturating_sub(1)),
+        KeyCode::PageDown => editor.list.scroll_down_by(10),
+        KeyCode::PageUp => editor.list.scroll_up_by(10),
+        KeyCode::Enter if editor.letters.len() == length => {
+            let guess: String = editor.letters.iter().collect();
+            let pattern: String = editor.colors.iter().collect();
+            if hard && let Some(reason) = violates_hard_mode(&guess, solver.history()) {
+                editor.message = format!("Сложный режим: {}", reason);
+                return true;
+            }
+            let before = solver.filtered().len();
+            solver.apply(&guess, &pattern);
+            editor.message = format_elimination(before, solver.filtered()).replace('\n', " ");
+            editor.clear();
+        }
+        KeyCode::Enter => editor.message = format!("Догадка должна состоять из {} букв.", length),
+        _ => {}
+    }
+    true
+}
+
+fn cell(letter: char, color: char, selected: bool) -> Span<'static> {
+    let background = match color {
+        'g' => Color::Green,
+        'y' => Color::Yellow,
+        _ => Color::DarkGray,
+    };
+    let mut style = Style::default().fg(Color::Black).bg(background);
+    if selected {
+        style = style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+    }
+    Span::styled(format!(" {} ", letter.to_uppercase()), style)
+}
+
+fn draw(frame: &mut Frame, solver: &Solver, editor: &mut Editor, length: usize) {
+    let [main, side] = Layout::horizontal([Constraint::Min(40), Constraint::Length(24)]).areas(frame.area());
+    let [grid, status, help] =
+        Layout::vertical([Constraint::Min(3), Constraint::Length(3), Constraint::Length(4)]).areas(main);
+
+    let mut rows: Vec<Line> = solver
+        .history()
+        .iter()
+        .map(|(guess, pattern)| {
+            Line::from(
+                guess
+                    .chars()
+                    .zip(pattern.chars())
+                    .map(|(letter, color)| cell(letter, color.to_ascii_lowercase(), false))
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .collect();
+    let mut current: Vec<Span> = editor
+        .letters
+        .iter()
+        .zip(&editor.colors)
+        .enumerate()
+        .map(|(i, (&letter, &color))| cell(letter, color, i == editor.cursor))
+        .collect();
+    current.extend((editor.letters.len()..length).map(|_| Span::raw(" _ ")));
+    rows.push(Line::from(current));
+    frame.render_widget(Paragraph::new(rows).block(Block::bordered().title(" Догадки ")), grid);
+
+    let status_text = format!("Подходит {} слов. {}", solver.filtered().len(), editor.message);
+    frame.render_widget(Paragraph::new(status_text).block(Block::bordered()), status);
+    frame.render_widget(Paragraph::new(HELP), help);
+
+    let title = format!(" Слова: {} ", solver.filtered().len());
+    let words: Vec<String> = solver.ranked().into_iter().cloned().collect();
+    let list = List::new(words)
+        .block(Block::bordered().title(title))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    frame.render_stateful_widget(list, side, &mut editor.list);
+}
@@ -0,0 +1,23 @@
+use crate::filters::filter_words;
+use crate::pattern::{matches_pattern, normalize_pattern, score_guess, validate_pattern};
+use wasm_bindgen::prelude::*;
+
+/// `matchesPattern(word, guess, pattern)` для JavaScript: подходит ли `word` под догадку и шаблон.
+#[wasm_bindgen(js_name = matchesPattern)]
+pub fn matches_pattern_js(word: &str, guess: &str, pattern: &str) -> bool {
+    matches_pattern(&word.to_lowercase(), &guess.to_lowercase(), &normalize_pattern(pattern))
+}
+
+/// `scoreGuess(answer, guess)` для JavaScript: шаблон g/y/b, который выдаст Wordle.
+#[wasm_bindgen(js_name = scoreGuess)]
+pub fn score_guess_js(answer: &str, guess: &str) -> String {
+    score_guess(&answer.to_lowercase(), &guess.to_lowercase())
+}
+
+/// `filterWords(dictionary, guess, pattern)` для JavaScript: подходящие слова из текста словаря.
+/// Неверный шаблон выбрасывает исключение с тем же текстом, что и в командной строке.
+#[wasm_bindgen(js_name = filterWords)]
+pub fn filter_words_js(dictionary: &str, guess: &str, pattern: &str) -> Result<Vec<String>, JsError> {
+    validate_pattern(guess, &normalize_pattern(pattern)).map_err(|e| JsError::new(&e))?;
+    Ok(filter_words(dictionary, guess, pattern))
+}
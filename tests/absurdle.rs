@@ -0,0 +1,33 @@
+use wordle_solver::{Absurdle, evil_pattern};
+
+fn words(list: &[&str]) -> Vec<String> {
+    list.iter().map(|w| w.to_string()).collect()
+}
+
+#[test]
+fn evil_pattern_keeps_largest_bucket() {
+    let candidates = words(&["crate", "trace", "slime", "smile", "plumb"]);
+    assert_eq!(evil_pattern("crate", &candidates), "bbbbg");
+}
+
+#[test]
+fn ties_prefer_fewer_greens_then_yellows() {
+    assert_eq!(evil_pattern("crate", &words(&["slime", "plumb"])), "bbbbb");
+    assert_eq!(evil_pattern("slime", &words(&["slime", "smile"])), "gygyg");
+    assert_eq!(evil_pattern("crate", &[]), "bbbbb");
+}
+
+#[test]
+fn game_is_won_only_when_one_word_is_forced() {
+    let mut game = Absurdle::new(&words(&["crate", "trace", "slime", "smile", "plumb"]));
+    assert_eq!(game.guess("crate"), "bbbbg");
+    assert_eq!(game.candidates(), words(&["slime", "smile"]));
+
+    assert_eq!(game.guess("slime"), "gygyg");
+    assert!(!game.is_won());
+    assert_eq!(game.candidates(), words(&["smile"]));
+
+    assert_eq!(game.guess("smile"), "ggggg");
+    assert!(game.is_won());
+    assert_eq!(game.history().len(), 3);
+}
@@ -0,0 +1,78 @@
+use wordle_solver::{
+    coverage_score, format_by_letter, format_frequencies, format_heatmap, letter_frequencies, rank_probes,
+};
+
+fn words(list: &[&str]) -> Vec<String> {
+    list.iter().map(|w| w.to_string()).collect()
+}
+
+#[test]
+fn counts_overall_and_per_position() {
+    let frequencies = letter_frequencies(&words(&["abbey", "crate"]));
+    assert_eq!(frequencies.overall[&'b'], 2);
+    assert_eq!(frequencies.overall[&'e'], 2);
+    assert_eq!(frequencies.positional.len(), 5);
+    assert_eq!(frequencies.positional[0][&'a'], 1);
+    assert_eq!(frequencies.positional[2][&'a'], 1);
+    assert_eq!(frequencies.sorted()[..2], [('a', 2), ('b', 2)]);
+}
+
+#[test]
+fn table_has_row_per_letter() {
+    let table = format_frequencies(&letter_frequencies(&words(&["ab", "ba"])));
+    assert_eq!(
+        table,
+        "Частоты букв: a 2, b 2\n       1     2\na      1     1\nb      1     1"
+    );
+}
+
+#[test]
+fn coverage_counts_distinct_letters_with_vowels_doubled() {
+    assert_eq!(coverage_score("crate"), 7);
+    assert_eq!(coverage_score("CRATE"), 7);
+    assert_eq!(coverage_score("eerie"), 5);
+    assert_eq!(coverage_score("ёлка"), 6);
+}
+
+#[test]
+fn probes_are_ranked_by_coverage_keeping_order_on_ties() {
+    let list = words(&["eerie", "crate", "trace"]);
+    let ranked: Vec<(&str, u32)> = rank_probes(&list)
+        .into_iter()
+        .map(|(word, score)| (word.as_str(), score))
+        .collect();
+    assert_eq!(ranked, [("crate", 7), ("trace", 7), ("eerie", 5)]);
+}
+
+#[test]
+fn by_letter_groups_by_position_with_scaled_bars() {
+    let frequencies = letter_frequencies(&words(&["slate", "shine", "crate", "stove"]));
+    let histogram = format_by_letter(&frequencies, 0);
+    assert_eq!(
+        histogram.lines().collect::<Vec<_>>(),
+        [
+            "Буквы на позиции 1:",
+            &format!("S:     3 {}", "█".repeat(40)),
+            &format!("C:     1 {}", "█".repeat(14)),
+        ]
+    );
+    assert!(format_by_letter(&frequencies, 4).starts_with("Буквы на позиции 5:\nE:     4 "));
+    assert_eq!(format_by_letter(&letter_frequencies(&[]), 0), "Подходящих слов нет.");
+}
+
+#[test]
+fn heatmap_shows_top_letters_per_position_as_percentages() {
+    let frequencies = letter_frequencies(&words(&["slate", "shine", "crate", "stove"]));
+    assert_eq!(frequencies.ranked_at(1), [('h', 1), ('l', 1), ('r', 1), ('t', 1)]);
+    assert_eq!(
+        format_heatmap(&frequencies).lines().collect::<Vec<_>>(),
+        [
+            "Вероятности букв по позициям (4 слов):",
+            "   1       2       3       4       5",
+            "1. S  75%  H  25%  A  50%  T  50%  E 100%",
+            "2. C  25%  L  25%  I  25%  N  25%  —",
+            "3. —       R  25%  O  25%  V  25%  —",
+        ]
+    );
+    assert_eq!(format_heatmap(&letter_frequencies(&[])), "Подходящих слов нет.");
+}
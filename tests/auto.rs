@@ -0,0 +1,120 @@
+use std::process::Command;
+
+fn run(args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_wordle-solver"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn auto_solves_known_answer_deterministically() {
+    let output = run(&["--auto", "--answer", "crate", "--color", "never"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(
+        lines,
+        [
+            "1. raise yybbg — осталось 26 слов",
+            "2. track yggyb — осталось 1 слов",
+            "3. crate ggggg — осталось 1 слов",
+            "Решено за 3 догадок.",
+        ]
+    );
+}
+
+#[test]
+fn auto_starts_with_the_forced_first_guess() {
+    let output = run(&[
+        "--auto",
+        "--answer",
+        "crate",
+        "--color",
+        "never",
+        "--first-guess",
+        "SLATE",
+    ]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("1. slate bbggg — осталось 6 слов\n"), "{}", stdout);
+    assert!(
+        stdout.ends_with("crate ggggg — осталось 1 слов\nРешено за 3 догадок.\n"),
+        "{}",
+        stdout
+    );
+}
+
+#[test]
+fn first_guess_must_be_a_known_word_and_needs_a_solo_mode() {
+    let output = run(&["--auto", "--answer", "crate", "--first-guess", "zzzzz"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("--first-guess: слова 'zzzzz' нет")
+    );
+
+    let output = run(&["-g", "crate", "-p", "bbbbb", "--first-guess", "slate"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("только с --simulate или --auto")
+    );
+}
+
+#[test]
+fn auto_rejects_unknown_answer() {
+    let output = run(&["--auto", "--answer", "qqqqq"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+}
+
+#[test]
+fn difficulty_reports_label_and_guesses() {
+    let output = run(&["--difficulty", "Crate"]);
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "crate: лёгкое (3 догадок)\nraise → track → crate\n"
+    );
+
+    let output = run(&["--difficulty", "qqqqq"]);
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn auto_prints_the_emoji_grid() {
+    let output = run(&["--auto", "--answer", "crate", "--color", "never", "--emoji"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.ends_with("Решено за 3 догадок.\n3/6\n🟨🟨⬛⬛🟩\n🟨🟩🟩🟨⬛\n🟩🟩🟩🟩🟩\n"),
+        "{}",
+        stdout
+    );
+}
+
+#[test]
+fn auto_with_keep_case_stops_on_the_all_green_pattern() {
+    let path = std::env::temp_dir().join(format!("wordle-solver-auto-keep-case-{}.txt", std::process::id()));
+    std::fs::write(&path, "Polish\nbarons\n").unwrap();
+    let output = run(&[
+        "-d",
+        path.to_str().unwrap(),
+        "--keep-case",
+        "--auto",
+        "--answer",
+        "polish",
+        "--color",
+        "never",
+    ]);
+    std::fs::remove_file(&path).unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "1. Polish gggggg — осталось 1 слов\nРешено за 1 догадок.\n"
+    );
+}
@@ -0,0 +1,89 @@
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn bench_csv_writes_a_row_per_answer() {
+    let dir = std::env::temp_dir();
+    let dictionary = dir.join(format!("wordle-solver-bench-words-{}.txt", std::process::id()));
+    let csv = dir.join(format!("wordle-solver-bench-{}.csv", std::process::id()));
+    fs::write(&dictionary, "crate\ntrace\nslate\nplate\ncrane\nbrine\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_wordle-solver"))
+        .arg("--simulate")
+        .arg("--dictionary")
+        .arg(&dictionary)
+        .arg("--bench-csv")
+        .arg(&csv)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let text = fs::read_to_string(&csv).unwrap();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines[0], "answer,guesses,solved");
+    assert_eq!(lines.len(), 7);
+    for (line, answer) in lines[1..]
+        .iter()
+        .zip(["crate", "trace", "slate", "plate", "crane", "brine"])
+    {
+        let fields: Vec<&str> = line.split(',').collect();
+        assert_eq!(fields[0], answer);
+        assert!(fields[1].parse::<usize>().unwrap() >= 1);
+        assert_eq!(fields[2], "true");
+    }
+    fs::remove_file(dictionary).ok();
+    fs::remove_file(csv).ok();
+}
+
+#[test]
+fn bench_csv_requires_simulate() {
+    let output = Command::new(env!("CARGO_BIN_EXE_wordle-solver"))
+        .args(["--bench-csv", "out.csv", "-g", "crate", "-p", "bbbbb"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
+
+#[test]
+fn bench_csv_adds_opener_column_for_first_guesses() {
+    let dir = std::env::temp_dir();
+    let dictionary = dir.join(format!("wordle-solver-bench-openers-{}.txt", std::process::id()));
+    let csv = dir.join(format!("wordle-solver-bench-openers-{}.csv", std::process::id()));
+    fs::write(&dictionary, "crate\ntrace\nslate\nplate\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_wordle-solver"))
+        .args([
+            "--simulate",
+            "--first-guess",
+            "plate",
+            "--first-guess",
+            "crate",
+            "--dictionary",
+        ])
+        .arg(&dictionary)
+        .arg("--bench-csv")
+        .arg(&csv)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.starts_with("Первая догадка: plate\nСыграно партий: 4\n"),
+        "{}",
+        stdout
+    );
+    assert!(
+        stdout.contains("\n\nПервая догадка: crate\nСыграно партий: 4\n"),
+        "{}",
+        stdout
+    );
+
+    let text = fs::read_to_string(&csv).unwrap();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines[0], "opener,answer,guesses,solved");
+    assert_eq!(lines.len(), 9);
+    assert!(lines[1].starts_with("plate,crate,"));
+    assert_eq!(lines[8], "crate,plate,3,true");
+    fs::remove_file(dictionary).ok();
+    fs::remove_file(csv).ok();
+}
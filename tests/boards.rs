@@ -0,0 +1,51 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+use wordle_solver::{Boards, format_boards};
+
+fn words(list: &[&str]) -> Vec<String> {
+    list.iter().map(|w| w.to_string()).collect()
+}
+
+#[test]
+fn boards_filter_independently() {
+    let dictionary = words(&["crate", "trace", "slime", "smile"]);
+    let mut boards = Boards::new(2, &dictionary, &dictionary);
+    assert_eq!(boards.unsolved(), [0, 1]);
+
+    assert_eq!(boards.apply(0, "crate", "ggggg"), 1);
+    assert_eq!(boards.apply(1, "crate", "bbbbg"), 2);
+    assert!(boards.is_solved(0));
+    assert_eq!(boards.unsolved(), [1]);
+    assert_eq!(
+        format_boards(&boards),
+        "Поле 1: решено — crate\nПоле 2: подходит 2 слов\nРешено полей: 1 из 2."
+    );
+
+    boards.reset();
+    assert_eq!(boards.board(0).filtered().len(), 4);
+    assert_eq!(boards.unsolved(), [0, 1]);
+}
+
+#[test]
+fn cli_asks_pattern_only_for_unsolved_boards() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_wordle-solver"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["--boards", "2"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(b"crate\nggggg\nbbbbg\nslime\nggggg\n")
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(stdout.contains("Поле 1: решено — crate"), "{}", stdout);
+    assert_eq!(stdout.matches("Шаблон для поля 1").count(), 1, "{}", stdout);
+    assert!(stdout.contains("Решено полей: 2 из 2."), "{}", stdout);
+    assert!(stdout.ends_with("Все поля решены!\n"), "{}", stdout);
+}
@@ -0,0 +1,122 @@
+use std::fs;
+use std::process::{Command, Output};
+use wordle_solver::{
+    LetterCase, Solver, matches_pattern, matches_pattern_with, score_guess_with, validate_pattern,
+    validate_pattern_with,
+};
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_wordle-solver"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn case_sensitive_tells_proper_nouns_from_common_words() {
+    let path = std::env::temp_dir().join(format!("wordle-solver-case-{}.txt", std::process::id()));
+    fs::write(&path, "Nancy\nnancy\nfancy\n").unwrap();
+    let path = path.to_str().unwrap();
+    let filter = ["--format", "plain", "-d", path, "-g", "nancy", "-p", "ggggg"];
+
+    let output = run(&[&filter[..], &["--keep-case"]].concat());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "Nancy\nnancy\n");
+
+    let output = run(&[&filter[..], &["--case-sensitive"]].concat());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "nancy\n");
+
+    let output = run(&[
+        "--format",
+        "plain",
+        "-d",
+        path,
+        "-g",
+        "Nancy",
+        "-p",
+        "bGGGG",
+        "--case-sensitive",
+    ]);
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "nancy\nfancy\n");
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn letter_case_is_a_per_solver_setting() {
+    let words: Vec<String> = ["Nancy", "nancy", "fancy"].iter().map(|w| w.to_string()).collect();
+    let mut exact = Solver::new(words.clone());
+    exact.set_case(LetterCase::Sensitive);
+    let mut folded = Solver::new(words);
+
+    exact.apply("nancy", "ggggg");
+    folded.apply("nancy", "ggggg");
+    assert_eq!(exact.filtered(), ["nancy"]);
+    assert_eq!(folded.filtered(), ["Nancy", "nancy"]);
+
+    assert!(matches_pattern("US", "us", "gg"));
+    assert!(!matches_pattern_with("US", "us", "gg", LetterCase::Sensitive));
+    assert_eq!(score_guess_with("USA", "usA", LetterCase::Sensitive), "bbg");
+}
+
+#[test]
+fn case_sensitive_auto_play_tells_the_answer_from_its_capitalized_twin() {
+    let path = std::env::temp_dir().join(format!("wordle-solver-case-auto-{}.txt", std::process::id()));
+    fs::write(&path, "Polish\npolish\nbarons\n").unwrap();
+    let path = path.to_str().unwrap();
+
+    let output = run(&["-d", path, "--case-sensitive", "--suggest"]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Polish   1.585"), "{}", stdout);
+
+    let output = run(&[
+        "-d",
+        path,
+        "--case-sensitive",
+        "--auto",
+        "--answer",
+        "polish",
+        "--color",
+        "never",
+    ]);
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "1. Polish bggggg — осталось 1 слов\n2. polish gggggg — осталось 1 слов\nРешено за 2 догадок.\n"
+    );
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn case_sensitive_keeps_the_case_of_answers_and_typed_words() {
+    let path = std::env::temp_dir().join(format!("wordle-solver-case-words-{}.txt", std::process::id()));
+    fs::write(&path, "Polish\npolish\nbarons\n").unwrap();
+    let path = path.to_str().unwrap();
+
+    let output = run(&[
+        "-d",
+        path,
+        "--case-sensitive",
+        "--auto",
+        "--answer",
+        "Polish",
+        "--color",
+        "never",
+    ]);
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "1. Polish gggggg — осталось 1 слов\nРешено за 1 догадок.\n"
+    );
+
+    let output = run(&["-d", path, "--case-sensitive", "--difficulty", "Polish"]);
+    assert!(String::from_utf8(output.stdout).unwrap().starts_with("Polish: "));
+
+    let output = run(&["-d", path, "--case-sensitive", "--starts", "Po", "--format", "plain"]);
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "Polish\n");
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn pattern_consistency_follows_the_letter_case() {
+    assert!(validate_pattern("Aa", "by").is_err());
+    assert_eq!(validate_pattern_with("Aa", "by", LetterCase::Sensitive), Ok(()));
+}
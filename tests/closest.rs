@@ -0,0 +1,52 @@
+use std::process::Command;
+use wordle_solver::{closest_words, edit_distance, validate_known};
+
+fn words(list: &[&str]) -> Vec<String> {
+    list.iter().map(|w| w.to_string()).collect()
+}
+
+#[test]
+fn edit_distance_counts_insertions_deletions_and_substitutions() {
+    assert_eq!(edit_distance("crate", "crate"), 0);
+    assert_eq!(edit_distance("crate", "Crane"), 1);
+    assert_eq!(edit_distance("crate", "rate"), 1);
+    assert_eq!(edit_distance("kitten", "sitting"), 3);
+    assert_eq!(edit_distance("", "abc"), 3);
+    assert_eq!(edit_distance("ёлка", "елка"), 1);
+}
+
+#[test]
+fn closest_words_are_sorted_by_distance_and_limited() {
+    let dictionary = words(&["slate", "crane", "crate", "trace", "brute", "cra"]);
+    assert_eq!(
+        closest_words("crate", &dictionary, 10),
+        words(&["crane", "slate", "trace", "brute", "cra"])
+    );
+    assert_eq!(closest_words("crrte", &dictionary, 2), words(&["crate", "crane"]));
+    assert!(closest_words("zzzzz", &dictionary, 10).is_empty());
+}
+
+#[test]
+fn validate_known_suggests_similar_words() {
+    let dictionary = words(&["crane", "crate", "slate"]);
+    assert!(validate_known("CRATE", &dictionary, 5).is_ok());
+    let err = validate_known("crrte", &dictionary, 5).unwrap_err();
+    assert!(err.contains("возможно, имелось в виду: crate, crane"), "{}", err);
+    assert!(
+        !validate_known("zzzzz", &dictionary, 5)
+            .unwrap_err()
+            .contains("возможно")
+    );
+}
+
+#[test]
+fn strict_guess_rejects_unknown_batch_guess() {
+    let output = Command::new(env!("CARGO_BIN_EXE_wordle-solver"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["--strict-guess", "-g", "crrte", "-p", "bbbbb"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("crate"), "{}", stderr);
+}
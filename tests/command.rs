@@ -0,0 +1,172 @@
+use wordle_solver::{InteractiveCommand, PatternMap, parse_command, parse_command_with, parse_guess_input};
+
+#[test]
+fn aliases_dispatch_case_insensitively() {
+    assert_eq!(parse_command("s"), InteractiveCommand::Show(""));
+    assert_eq!(parse_command("SHOW"), InteractiveCommand::Show(""));
+    assert_eq!(parse_command("show more"), InteractiveCommand::Show("more"));
+    assert_eq!(parse_command("s 3"), InteractiveCommand::Show("3"));
+    assert_eq!(parse_command("N"), InteractiveCommand::New);
+    assert_eq!(parse_command("q"), InteractiveCommand::Exit);
+    assert_eq!(parse_command("Exit"), InteractiveCommand::Exit);
+    assert_eq!(parse_command("?"), InteractiveCommand::Help);
+    assert_eq!(parse_command("paste"), InteractiveCommand::Paste);
+    assert_eq!(parse_command("Redo"), InteractiveCommand::Redo);
+    assert_eq!(parse_command("positions"), InteractiveCommand::Positions);
+    assert_eq!(parse_command("random"), InteractiveCommand::Random);
+    assert_eq!(parse_command("probe"), InteractiveCommand::Probe { all: false });
+    assert_eq!(parse_command("probe ALL"), InteractiveCommand::Probe { all: true });
+    assert_eq!(parse_command("help"), InteractiveCommand::Help);
+}
+
+#[test]
+fn empty_input_exits() {
+    assert_eq!(parse_command(""), InteractiveCommand::Exit);
+    assert_eq!(parse_command("  \n"), InteractiveCommand::Exit);
+}
+
+#[test]
+fn commands_with_arguments() {
+    assert_eq!(parse_command("split crate"), InteractiveCommand::Split("crate"));
+    assert_eq!(parse_command("heatmap"), InteractiveCommand::Heatmap);
+    assert_eq!(parse_command("BEST"), InteractiveCommand::Best);
+    assert_eq!(parse_command("history"), InteractiveCommand::History);
+    assert_eq!(parse_command("pick 2"), InteractiveCommand::Pick("2"));
+    assert_eq!(parse_command("pick"), InteractiveCommand::Pick(""));
+    assert_eq!(
+        parse_command("expected crate trace"),
+        InteractiveCommand::Expected("crate trace")
+    );
+    assert_eq!(parse_command("anagram taerc"), InteractiveCommand::Anagram("taerc"));
+    assert_eq!(parse_command("try crate bgybb"), InteractiveCommand::Try("crate bgybb"));
+    assert_eq!(parse_command("Explain  trace"), InteractiveCommand::Explain("trace"));
+    assert_eq!(parse_command("check zzrzz"), InteractiveCommand::Check("zzrzz"));
+    assert_eq!(
+        parse_command("difficulty crate"),
+        InteractiveCommand::Difficulty("crate")
+    );
+    assert_eq!(parse_command("Regex  .*ight "), InteractiveCommand::Regex(".*ight"));
+    assert_eq!(parse_command("green 3 a"), InteractiveCommand::Green("3 a"));
+    assert_eq!(
+        parse_command("yellow bbgybg"),
+        InteractiveCommand::Guess("yellow bbgybg")
+    );
+    assert_eq!(parse_command("yellow 2 a"), InteractiveCommand::Yellow("2 a"));
+    assert_eq!(
+        parse_command("explain bgybbgy"),
+        InteractiveCommand::Guess("explain bgybbgy")
+    );
+    assert_eq!(parse_command("explain crate"), InteractiveCommand::Explain("crate"));
+    assert_eq!(parse_command("known bbgyb"), InteractiveCommand::Guess("known bbgyb"));
+    assert_eq!(parse_command("check gbbyb"), InteractiveCommand::Guess("check gbbyb"));
+    assert_eq!(parse_command("split bybgb"), InteractiveCommand::Guess("split bybgb"));
+    assert_eq!(parse_command("check gbbyb"), InteractiveCommand::Guess("check gbbyb"));
+    assert_eq!(parse_command("check 20010"), InteractiveCommand::Guess("check 20010"));
+    assert_eq!(parse_command("check crate"), InteractiveCommand::Check("crate"));
+    assert_eq!(parse_command("split bbgyb"), InteractiveCommand::Guess("split bbgyb"));
+    assert_eq!(parse_command("starts sl"), InteractiveCommand::Starts("sl"));
+    assert_eq!(parse_command("byletter"), InteractiveCommand::ByLetter(""));
+    assert_eq!(parse_command("ByLetter 3"), InteractiveCommand::ByLetter("3"));
+    assert_eq!(parse_command("Ends ght"), InteractiveCommand::Ends("ght"));
+    assert_eq!(parse_command("contains ig"), InteractiveCommand::Contains("ig"));
+    assert_eq!(
+        parse_command("session save s.json"),
+        InteractiveCommand::SessionSave("s.json")
+    );
+    assert_eq!(
+        parse_command("session LOAD s.json"),
+        InteractiveCommand::SessionLoad("s.json")
+    );
+    assert_eq!(parse_command("save words.txt"), InteractiveCommand::Save("words.txt"));
+    assert_eq!(parse_command("remove crate"), InteractiveCommand::Remove("crate"));
+    assert_eq!(parse_command("ADD crate"), InteractiveCommand::Add("crate"));
+}
+
+#[test]
+fn everything_else_is_a_guess() {
+    assert_eq!(parse_command("crate"), InteractiveCommand::Guess("crate"));
+    assert_eq!(parse_command("crate bgybb"), InteractiveCommand::Guess("crate bgybb"));
+    assert_eq!(parse_command("show me"), InteractiveCommand::Guess("show me"));
+    assert_eq!(parse_command("session"), InteractiveCommand::Guess("session"));
+    assert_eq!(parse_command("known"), InteractiveCommand::Known);
+    assert_eq!(parse_command("guess known"), InteractiveCommand::Guess("known"));
+    assert_eq!(
+        parse_command("Guess known bbgyb"),
+        InteractiveCommand::Guess("known bbgyb")
+    );
+    assert_eq!(parse_command("guess probe"), InteractiveCommand::Guess("probe"));
+    assert_eq!(parse_command("probe ybbgb"), InteractiveCommand::Guess("probe ybbgb"));
+    assert_eq!(parse_command("guess paste"), InteractiveCommand::Guess("paste"));
+    assert_eq!(parse_command("guess"), InteractiveCommand::Guess("guess"));
+    assert_eq!(parse_command("guess bbgyb"), InteractiveCommand::Guess("guess bbgyb"));
+}
+
+#[test]
+fn command_name_followed_by_a_pattern_is_a_guess() {
+    assert_eq!(parse_command("green ybbbg"), InteractiveCommand::Guess("green ybbbg"));
+    assert_eq!(
+        parse_command("green 🟨⬛⬛⬛🟩"),
+        InteractiveCommand::Guess("green 🟨⬛⬛⬛🟩")
+    );
+    assert_eq!(parse_command("green 3 a"), InteractiveCommand::Green("3 a"));
+    let map: PatternMap = "green=+,yellow=?,black=.".parse().unwrap();
+    assert_eq!(
+        parse_command_with("green ?...+", &map),
+        InteractiveCommand::Guess("green ?...+")
+    );
+}
+
+#[test]
+fn guess_input_is_checked_before_filtering() {
+    let map = PatternMap::default();
+    assert_eq!(parse_guess_input("crate", 5, &map), Ok(("crate", None)));
+    assert_eq!(
+        parse_guess_input("crate 🟩⬛🟨⬛⬛", 5, &map),
+        Ok(("crate", Some("gbybb".to_string())))
+    );
+    let err = parse_guess_input("crates", 5, &map).unwrap_err();
+    assert!(err.contains("догадка должна состоять из 5 букв"), "{}", err);
+    assert!(parse_guess_input("crates bgybbb", 5, &map).is_err());
+    assert!(
+        parse_guess_input("crate bgyb", 5, &map)
+            .unwrap_err()
+            .contains("длина шаблона")
+    );
+}
+
+#[test]
+fn every_command_with_an_argument_reads_a_pattern_of_its_length_as_a_guess() {
+    let keywords = [
+        "show",
+        "byletter",
+        "split",
+        "pick",
+        "expected",
+        "explain",
+        "check",
+        "try",
+        "difficulty",
+        "regex",
+        "anagram",
+        "starts",
+        "ends",
+        "contains",
+        "green",
+        "yellow",
+        "exclude",
+        "include",
+        "save",
+        "report",
+        "remove",
+        "add",
+        "session",
+        "probe",
+        "guess",
+        "known",
+    ];
+    for keyword in keywords {
+        let pattern: String = "bgy".chars().cycle().take(keyword.len()).collect();
+        let input = format!("{} {}", keyword, pattern);
+        assert_eq!(parse_command(&input), InteractiveCommand::Guess(&input), "{}", input);
+    }
+}
@@ -0,0 +1,14 @@
+use std::process::Command;
+
+#[test]
+fn every_shell_produces_a_script() {
+    for shell in ["bash", "zsh", "fish", "powershell", "elvish"] {
+        let output = Command::new(env!("CARGO_BIN_EXE_wordle-solver"))
+            .args(["completions", shell])
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{}", shell);
+        let script = String::from_utf8(output.stdout).unwrap();
+        assert!(script.contains("wordle-solver"), "{}", shell);
+    }
+}
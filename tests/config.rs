@@ -0,0 +1,94 @@
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+use wordle_solver::{CONFIG_FILE, Config};
+
+const SAMPLE: &str = r#"
+dictionary = "words.txt"
+answers = "embedded:wordle-La"
+frequency = "/tmp/frequency.txt"
+color = "never"
+strategy = "minimax"
+"#;
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("wordle-solver-config-{}-{}", name, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn run(dir: &PathBuf, args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_wordle-solver"))
+        .current_dir(dir)
+        .env("XDG_CONFIG_HOME", dir)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn parses_sample_config_and_rejects_unknown_keys() {
+    let config = Config::parse(SAMPLE).unwrap();
+    assert_eq!(config.dictionary.as_deref(), Some("words.txt"));
+    assert_eq!(config.answers.as_deref(), Some("embedded:wordle-La"));
+    assert_eq!(config.color.as_deref(), Some("never"));
+    assert_eq!(config.strategy.as_deref(), Some("minimax"));
+    assert_eq!(Config::parse("").unwrap(), Config::default());
+    assert!(Config::parse("dictonary = \"words.txt\"").is_err());
+}
+
+#[test]
+fn load_resolves_relative_paths_against_the_config_directory() {
+    let dir = temp_dir("load");
+    let path = dir.join(CONFIG_FILE);
+    fs::write(&path, SAMPLE).unwrap();
+
+    let config = Config::load(&path).unwrap();
+    assert_eq!(
+        config.dictionary,
+        Some(dir.join("words.txt").to_string_lossy().into_owned())
+    );
+    assert_eq!(config.answers.as_deref(), Some("embedded:wordle-La"));
+    assert_eq!(config.frequency.as_deref(), Some("/tmp/frequency.txt"));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn command_line_overrides_config() {
+    let dir = temp_dir("cli");
+    fs::write(dir.join("words.txt"), "crate\ntrace\nslime\n").unwrap();
+    fs::write(
+        dir.join(CONFIG_FILE),
+        "dictionary = \"words.txt\"\nstrategy = \"greedy\"\n",
+    )
+    .unwrap();
+
+    let output = run(&dir, &["--stdin", "--count"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8(output.stderr).unwrap().contains("strategy"));
+
+    let output = run(&dir, &["--stdin", "--count", "--strategy", "entropy"], "");
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "3\n");
+
+    let output = run(
+        &dir,
+        &[
+            "--stdin",
+            "--count",
+            "--strategy",
+            "entropy",
+            "-d",
+            "embedded:wordle-La",
+        ],
+        "",
+    );
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "2315\n");
+    fs::remove_dir_all(&dir).unwrap();
+}
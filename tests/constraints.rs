@@ -0,0 +1,141 @@
+use std::path::Path;
+use wordle_solver::{
+    Constraints, Solver, format_known, format_positions, load_dictionary, matches_pattern, parse_placement,
+    parse_position, score_guess, validate_letters,
+};
+
+fn words(list: &[&str]) -> Vec<String> {
+    list.iter().map(|w| w.to_string()).collect()
+}
+
+fn dictionary() -> Vec<String> {
+    load_dictionary(Path::new(env!("CARGO_MANIFEST_DIR")).join("wordle-La.txt")).unwrap()
+}
+
+#[test]
+fn empty_constraints_match_everything() {
+    let constraints = Constraints::new();
+    assert!(constraints.is_empty());
+    assert!(constraints.matches("crate"));
+    assert!(constraints.matches("abc"));
+}
+
+#[test]
+fn accumulates_greens_required_and_excluded() {
+    let mut constraints = Constraints::new();
+    constraints.add("crate", "bgbyy");
+    constraints.add("tiles", "gbbgb");
+
+    assert_eq!(constraints.greens(), [Some('t'), Some('r'), None, Some('e'), None]);
+    assert_eq!(constraints.required().collect::<Vec<_>>(), ['e', 'r', 't']);
+    assert_eq!(constraints.excluded().collect::<Vec<_>>(), ['a', 'c', 'i', 'l', 's']);
+    assert!(constraints.not_at(4).unwrap().contains(&'e'));
+    assert!(constraints.not_at(0).unwrap().contains(&'c'));
+    assert!(constraints.matches("truer"));
+    assert!(!constraints.matches("trees"));
+}
+
+#[test]
+fn same_result_as_replaying_matches_pattern() {
+    let dictionary = dictionary();
+    for answer in ["allee", "eerie", "speed", "crate", "mamma", "abbey"].iter().chain(
+        dictionary
+            .iter()
+            .step_by(97)
+            .map(|w| w.as_str())
+            .collect::<Vec<_>>()
+            .iter(),
+    ) {
+        let history: Vec<(String, String)> = ["speed", "eagle", "mamma", "crane"]
+            .iter()
+            .map(|guess| (guess.to_string(), score_guess(answer, guess)))
+            .collect();
+        let constraints = Constraints::from_history(&history);
+
+        for word in &dictionary {
+            let replayed = history
+                .iter()
+                .all(|(guess, pattern)| matches_pattern(word, guess, pattern));
+            assert_eq!(constraints.matches(word), replayed, "answer {answer}, word {word}");
+        }
+    }
+}
+
+#[test]
+fn known_letters_summary() {
+    let mut constraints = Constraints::new();
+    constraints.add("crate", "bgyyg");
+    constraints.add("slosh", "bbbbb");
+    assert_eq!(format_known(&constraints, 5), "_ R _ _ E\nесть: a,t\nнет: c,h,l,o,s");
+    assert_eq!(format_known(&Constraints::new(), 5), "_ _ _ _ _\nесть: \nнет: ");
+}
+
+#[test]
+fn excluded_and_included_letters_compose_with_patterns() {
+    let mut solver = Solver::new(words(&["crate", "trace", "react", "slime", "mount", "brick"]));
+    solver.apply("swill", "bbbbb");
+    assert_eq!(solver.filtered(), ["crate", "trace", "react", "mount"]);
+    assert!(solver.undo());
+
+    assert_eq!(solver.exclude("SL"), 5);
+    assert_eq!(solver.include("k"), 1);
+    assert_eq!(solver.filtered(), ["brick"]);
+    assert!(solver.history().is_empty());
+    assert_eq!(solver.constraints().excluded().collect::<String>(), "ls");
+    assert!(solver.undo());
+    assert_eq!(solver.filtered(), ["crate", "trace", "react", "mount", "brick"]);
+}
+
+#[test]
+fn letters_must_be_alphabetic() {
+    assert!(validate_letters("xqz").is_ok());
+    assert!(validate_letters("é").is_ok());
+    assert!(validate_letters("").is_err());
+    assert!(validate_letters("a1").is_err());
+}
+
+#[test]
+fn direct_green_and_yellow_placements() {
+    let mut solver = Solver::new(words(&["crate", "trace", "react", "carat", "chart"]));
+    assert_eq!(solver.set_green(0, 'C', 5), 3);
+    assert_eq!(solver.add_yellow(4, 't', 5), 1);
+    assert_eq!(solver.filtered(), ["crate"]);
+    assert_eq!(solver.constraints().greens(), [Some('c'), None, None, None, None]);
+    assert!(solver.undo());
+    assert_eq!(solver.filtered(), ["crate", "carat", "chart"]);
+}
+
+#[test]
+fn placements_are_validated() {
+    assert_eq!(parse_placement("3 a", 5), Ok((2, 'a')));
+    assert_eq!(parse_placement("5:É", 5), Ok((4, 'É')));
+    assert!(parse_placement("0 a", 5).is_err());
+    assert!(parse_placement("6 a", 5).is_err());
+    assert!(parse_placement("x a", 5).is_err());
+    assert!(parse_placement("3 ab", 5).is_err());
+    assert!(parse_placement("3", 5).is_err());
+    assert_eq!(parse_position("5", 5), Ok(4));
+    assert!(parse_position("0", 5).is_err());
+    assert!(parse_position("x", 5).is_err());
+}
+
+#[test]
+fn possible_positions_mid_game() {
+    let mut constraints = Constraints::from_history(&[
+        ("raise".to_string(), "yybbb".to_string()),
+        ("cloud".to_string(), "ybbbb".to_string()),
+        ("tread".to_string(), "bgbyb".to_string()),
+    ]);
+    assert_eq!(constraints.possible_positions('a', 5), [0, 2, 4]);
+    assert_eq!(constraints.possible_positions('r', 5), [1, 2, 3, 4]);
+    assert!(constraints.possible_positions('s', 5).is_empty());
+    assert_eq!(constraints.possible_positions('c', 5), [2, 3, 4]);
+    assert_eq!(
+        format_positions(&constraints, 5),
+        "A: позиции 1, 3, 5\nC: позиции 3, 4, 5\nR: на месте 2"
+    );
+
+    constraints.set_green(2, 'a', 5);
+    assert_eq!(constraints.possible_positions('c', 5), [3, 4]);
+    assert_eq!(format_positions(&Constraints::new(), 5), "Обязательных букв пока нет.");
+}
@@ -0,0 +1,246 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn run(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_wordle-solver"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    // Программа может завершиться с ошибкой, не дочитав ввод: тогда запись упирается в закрытый канал.
+    let _ = child.stdin.take().unwrap().write_all(stdin.as_bytes());
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn count_prints_only_the_number() {
+    let output = run(&["--stdin", "--count"], "crate bbbbb\n\nslime bbbbb\n");
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "37\n");
+}
+
+#[test]
+fn fail_on_empty_sets_exit_code() {
+    let output = run(&["--stdin", "--count", "--fail-on-empty"], "crate ggggg\ncrate bbbbb\n");
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "0\n");
+
+    let output = run(&["--stdin", "--count", "--fail-on-empty"], "crate ggggg\n");
+    assert!(output.status.success());
+}
+
+#[test]
+fn starts_ends_and_contains_compose_with_patterns() {
+    let output = run(&["--stdin", "--format", "plain", "--ends", "ight"], "");
+    let all = String::from_utf8(output.stdout).unwrap();
+    assert!(all.lines().count() > 1);
+    assert!(all.lines().all(|word| word.ends_with("ight")), "{}", all);
+
+    let output = run(
+        &["--stdin", "--format", "plain", "--starts", "N", "--contains", "ghT"],
+        "board bbbbb\n",
+    );
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "night\n");
+
+    let output = run(&["--stdin", "--count", "--starts", "s1"], "");
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn text_output_announces_a_single_answer() {
+    let output = run(&["--stdin"], "board bbbbb\n");
+    assert!(!String::from_utf8(output.stdout).unwrap().contains("Ответ:"));
+
+    let output = run(&["--stdin", "--starts", "n", "--contains", "ght"], "board bbbbb\n");
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Подходит 1 слов:\nnight\nОтвет: night\n"
+    );
+
+    let output = run(
+        &["--stdin", "--format", "plain", "--starts", "n", "--contains", "ght"],
+        "board bbbbb\n",
+    );
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "night\n");
+}
+
+#[test]
+fn verbose_logs_per_stage_filter_summary_to_stderr() {
+    let output = run(&["--stdin", "--count", "--verbose"], "raise bybbb\n");
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "92\n");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let line = stderr
+        .lines()
+        .find(|line| line.contains("фильтр: 2315 → 92 слов"))
+        .unwrap();
+    let stages: usize = ["зелёные — ", "жёлтые — ", "чёрные — "]
+        .iter()
+        .map(|label| {
+            let count = line.split(label).nth(1).unwrap();
+            count
+                .split(|c: char| !c.is_ascii_digit())
+                .next()
+                .unwrap()
+                .parse::<usize>()
+                .unwrap()
+        })
+        .sum();
+    assert_eq!(stages, 2315 - 92, "{}", line);
+}
+
+#[test]
+fn verbose_times_suggestions_on_stderr_only() {
+    let output = run(
+        &["--stdin", "--hint", "--format", "plain", "--verbose"],
+        "crate bbbbb\nslime bbbbb\n",
+    );
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(!stdout.contains("посчитаны"), "{}", stdout);
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("Подсказки посчитаны за ")
+    );
+
+    let output = run(
+        &["--stdin", "--hint", "--format", "plain"],
+        "crate bbbbb\nslime bbbbb\n",
+    );
+    assert!(!String::from_utf8(output.stderr).unwrap().contains("посчитаны"));
+}
+
+#[test]
+fn no_interactive_fallback_fails_without_filters() {
+    let output = run(&["--no-interactive-fallback"], "crate bbbbb\n");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("интерактивный режим отключён")
+    );
+
+    let output = run(
+        &["--no-interactive-fallback", "--stdin", "--count"],
+        "crate bbbbb\nslime bbbbb\n",
+    );
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "37\n");
+}
+
+#[test]
+fn show_removed_prints_reasons_to_stderr() {
+    let output = run(&["--stdin", "--count", "--show-removed", "1"], "crate bbbbb\n");
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "246\n");
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "crate bbbbb: Убраны (показано 1 из 2069):\n  aback — есть исключённая буква C\n"
+    );
+}
+
+#[test]
+fn repeated_dictionary_flags_are_merged() {
+    let extra = std::env::temp_dir().join(format!("wordle-solver-extra-{}.txt", std::process::id()));
+    std::fs::write(&extra, "zesty\ncrate\nzonal\n").unwrap();
+    let extra = extra.to_str().unwrap();
+    let output = run(
+        &[
+            "-d",
+            "tests/fixtures/messy.txt",
+            "-d",
+            extra,
+            "--stdin",
+            "--format",
+            "plain",
+        ],
+        "",
+    );
+    std::fs::remove_file(extra).unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "crate\ntrace\nreact\nslime\nzesty\nzonal\n"
+    );
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("Объединено словарей: 2, всего 6 разных слов.")
+    );
+}
+
+#[test]
+fn dictionary_can_be_piped_through_stdin() {
+    let words = "crate\ntrace\nsulky\nslime\n";
+    let output = run(&["-d", "-", "-g", "crate", "-p", "bbbbb", "--format", "plain"], words);
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "sulky\n");
+
+    let output = run(&["-d", "-", "--count"], words);
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "4\n");
+
+    for args in [&["-d", "-", "-i"][..], &["-d", "-", "--stdin"], &["-d", "-"]] {
+        let output = run(args, words);
+        assert_eq!(output.status.code(), Some(1), "{:?}", args);
+        assert!(output.stdout.is_empty());
+    }
+}
+
+#[test]
+fn echo_history_prints_applied_pairs_to_stderr() {
+    let output = run(
+        &["--stdin", "--count", "--echo-history", "--color", "never"],
+        "raise yybbg\ntrack yggyb\n",
+    );
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "1\n");
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("Догадки (2):\n1. raise yybbg\n2. track yggyb\n")
+    );
+}
+
+#[test]
+fn profile_summarizes_stages_on_exit() {
+    let output = run(
+        &["--stdin", "--hint", "--format", "plain", "--profile"],
+        "raise yybbg\n",
+    );
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let summary = &stderr[stderr.find("Профиль:").expect(&stderr)..];
+    for stage in [
+        "загрузка словаря: 1 раз",
+        "применение догадок: 1 раз",
+        "подсказки: 1 раз",
+        "всего: ",
+    ] {
+        assert!(summary.contains(stage), "{}", summary);
+    }
+
+    let output = run(&["--stdin", "--count"], "raise yybbg\n");
+    assert!(!String::from_utf8(output.stderr).unwrap().contains("Профиль"));
+}
+
+#[test]
+fn replay_reports_each_step_and_prints_the_result() {
+    let output = run(&["--replay", "tests/fixtures/replay.txt", "--format", "plain"], "");
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "crate\n");
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "Шаг 1: raise yybbg — осталось 26 слов\nШаг 2: track yggyb — осталось 1 слов\n"
+    );
+
+    let output = run(&["--replay", "tests/no-such-replay.txt"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("--replay tests/no-such-replay.txt")
+    );
+}
@@ -0,0 +1,38 @@
+use wordle_solver::diagnose_empty;
+
+fn history(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs.iter().map(|(g, p)| (g.to_string(), p.to_string())).collect()
+}
+
+#[test]
+fn consistent_history_has_no_findings() {
+    assert!(diagnose_empty(&history(&[("crate", "bgbyy"), ("tiles", "gbbgb")])).is_empty());
+    assert!(diagnose_empty(&[]).is_empty());
+}
+
+#[test]
+fn green_then_black_letter() {
+    let findings = diagnose_empty(&history(&[("crate", "gbbbb"), ("chock", "bbbbb")]));
+    assert!(
+        findings
+            .iter()
+            .any(|f| f.contains("буква C найдена в ходе 1, но в ходе 2 отмечена как отсутствующая"))
+    );
+    assert!(findings.iter().any(|f| f.contains("позиция 1")));
+}
+
+#[test]
+fn two_greens_on_one_position() {
+    let findings = diagnose_empty(&history(&[("crate", "gbbbb"), ("slate", "gbbbb")]));
+    assert!(
+        findings
+            .iter()
+            .any(|f| f.contains("в ходе 1 зелёная C, а в ходе 2 зелёная S"))
+    );
+}
+
+#[test]
+fn required_more_often_than_allowed() {
+    let findings = diagnose_empty(&history(&[("eerie", "yybbb"), ("speed", "bbybb")]));
+    assert!(findings.iter().any(|f| f.contains("минимум 2")));
+}
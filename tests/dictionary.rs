@@ -0,0 +1,60 @@
+use std::path::{Path, PathBuf};
+use wordle_solver::{
+    DictionaryOptions, load_dictionary, load_dictionary_with, merge_dictionaries, parse_dictionary,
+    parse_dictionary_with,
+};
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(name)
+}
+
+#[test]
+fn messy_file_is_normalized() {
+    let words = load_dictionary(fixture("messy.txt")).unwrap();
+    assert_eq!(words, ["crate", "trace", "react", "slime"]);
+}
+
+#[test]
+fn keep_case_preserves_spelling_but_still_cleans_up() {
+    let options = DictionaryOptions {
+        keep_case: true,
+        ..DictionaryOptions::default()
+    };
+    let (words, rejected) = load_dictionary_with(fixture("messy.txt"), options).unwrap();
+    assert_eq!(rejected, 0);
+    assert_eq!(words, ["crate", "Trace", "react", "CRATE", "slime", "trace"]);
+}
+
+#[test]
+fn bom_is_stripped() {
+    assert_eq!(parse_dictionary("\u{feff}crate\ntrace"), ["crate", "trace"]);
+}
+
+#[test]
+fn non_alphabetic_entries_are_dropped_and_counted() {
+    let text = "crate\nro-ad\nab12c\nnew york\nétude\nniño\nslime";
+    let (words, rejected) = parse_dictionary_with(text, DictionaryOptions::default());
+    assert_eq!(words, ["crate", "étude", "niño", "slime"]);
+    assert_eq!(rejected, 3);
+}
+
+#[test]
+fn allow_nonalpha_keeps_everything() {
+    let options = DictionaryOptions {
+        allow_nonalpha: true,
+        ..DictionaryOptions::default()
+    };
+    let (words, rejected) = parse_dictionary_with("crate\nro-ad\nab12c", options);
+    assert_eq!(words, ["crate", "ro-ad", "ab12c"]);
+    assert_eq!(rejected, 0);
+}
+
+#[test]
+fn merged_dictionaries_keep_first_occurrence_order() {
+    let merged = merge_dictionaries([
+        parse_dictionary("crate\ntrace\n"),
+        parse_dictionary("slime\ncrate\nreact\n"),
+    ]);
+    assert_eq!(merged, ["crate", "trace", "slime", "react"]);
+    assert!(merge_dictionaries(Vec::new()).is_empty());
+}
@@ -0,0 +1,19 @@
+use std::path::Path;
+use wordle_solver::{EMBEDDED_DICTIONARIES, embedded_dictionary, load_dictionary, parse_dictionary};
+
+#[test]
+fn embedded_default_matches_file() {
+    let file = load_dictionary(Path::new(env!("CARGO_MANIFEST_DIR")).join("wordle-La.txt")).unwrap();
+    assert_eq!(embedded_dictionary("wordle-La").unwrap(), file);
+}
+
+#[test]
+fn unknown_embedded_name() {
+    assert!(embedded_dictionary("missing").is_none());
+    assert!(!EMBEDDED_DICTIONARIES.is_empty());
+}
+
+#[test]
+fn parse_splits_lines() {
+    assert_eq!(parse_dictionary("crate\ntrace\n"), ["crate", "trace"]);
+}
@@ -0,0 +1,58 @@
+use std::path::Path;
+use wordle_solver::{
+    EncodedWord, letter_bit, load_dictionary, matches_encoded, matches_pattern, pattern_code, score_guess,
+};
+
+fn code_of(pattern: &str) -> u64 {
+    pattern.chars().rev().fold(0, |code, color| {
+        code * 3
+            + match color {
+                'g' => 2,
+                'y' => 1,
+                _ => 0,
+            }
+    })
+}
+
+#[test]
+fn mask_marks_present_letters() {
+    let word = EncodedWord::new("abbey");
+    assert_eq!(
+        word.mask(),
+        letter_bit('a') | letter_bit('b') | letter_bit('e') | letter_bit('y')
+    );
+    assert!(word.contains('b'));
+    assert!(!word.contains('z'));
+    assert_eq!(word.to_string(), "abbey");
+}
+
+#[test]
+fn non_ascii_letters_are_found_without_mask() {
+    let word = EncodedWord::new("été");
+    assert_eq!(word.len(), 3);
+    assert!(word.contains('é'));
+    assert!(!word.contains('e'));
+}
+
+#[test]
+fn pattern_code_agrees_with_score_guess() {
+    let dictionary = load_dictionary(Path::new(env!("CARGO_MANIFEST_DIR")).join("wordle-La.txt")).unwrap();
+    for guess in dictionary.iter().step_by(41) {
+        let encoded_guess = EncodedWord::new(guess);
+        for answer in dictionary.iter().step_by(7) {
+            let pattern = score_guess(answer, guess);
+            assert_eq!(
+                pattern_code(&EncodedWord::new(answer), &encoded_guess),
+                code_of(&pattern)
+            );
+
+            let pattern_chars: Vec<char> = pattern.chars().collect();
+            for word in dictionary.iter().step_by(53) {
+                assert_eq!(
+                    matches_encoded(&EncodedWord::new(word), &encoded_guess, &pattern_chars),
+                    matches_pattern(word, guess, &pattern)
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use wordle_solver::{
+    FilterResult, LetterCase, format_attempts, format_elimination, format_page, format_preview, format_removed,
+    format_solution, format_uncertainty, page_count,
+};
+
+#[test]
+fn serializes_count_and_words() {
+    let words = vec!["crate".to_string(), "trace".to_string()];
+    let json = serde_json::to_string(&FilterResult::new(&words)).unwrap();
+    assert_eq!(json, r#"{"count":2,"words":["crate","trace"]}"#);
+}
+
+#[test]
+fn serializes_empty_result() {
+    let json = serde_json::to_string(&FilterResult::new(&[])).unwrap();
+    assert_eq!(json, r#"{"count":0,"words":[]}"#);
+}
+
+#[test]
+fn elimination_message() {
+    let remaining = vec!["crate".to_string(), "trace".to_string()];
+    assert!(format_elimination(10, &remaining).starts_with("Убрано 8 слов, осталось 2.\nОсталось два слова: "));
+    assert!(format_elimination(10, &remaining[..1]).ends_with("\nОтвет: crate"));
+    assert!(format_elimination(10, &[]).contains("противоречат"));
+}
+
+#[test]
+fn uncertainty_message() {
+    assert_eq!(
+        format_uncertainty(16, 8, 2),
+        "Неопределённость: осталось 1.0 бит, разрешено 3.0 из 4.0 (последняя догадка дала 2.0 бит)."
+    );
+    assert!(format_uncertainty(16, 2, 1).starts_with("Неопределённость: осталось 0.0 бит, разрешено 4.0"));
+    assert!(format_uncertainty(16, 2, 0).contains("подходящих слов нет"));
+}
+
+#[test]
+fn attempts_count_down_and_warn_on_last() {
+    assert_eq!(format_attempts(1, 6, 40), "Попытка 1 из 6, осталось 5.");
+    assert!(format_attempts(5, 6, 3).contains("последняя попытка"));
+    assert!(format_attempts(6, 6, 3).starts_with("Попытки закончились (6 из 6)"));
+    assert!(format_attempts(8, 6, 0).starts_with("Попытки закончились (8 из 6)"));
+    assert_eq!(format_attempts(6, 6, 1), "Попытка 6 из 6.");
+}
+
+#[test]
+fn solution_is_announced_for_one_or_two_words() {
+    assert_eq!(format_solution(&["crate"]), Some("Ответ: crate".to_string()));
+    assert!(
+        format_solution(&["crate", "trace"])
+            .unwrap()
+            .contains("crate или trace")
+    );
+    assert_eq!(format_solution(&["crate", "trace", "react"]), None);
+    assert_eq!(format_solution(&[] as &[&str]), None);
+}
+
+#[test]
+fn removed_words_are_listed_with_reasons() {
+    let before: Vec<String> = ["crate", "trace", "slate", "brine"]
+        .iter()
+        .map(|w| w.to_string())
+        .collect();
+    let after = vec!["trace".to_string()];
+    assert_eq!(
+        format_removed(&before, &after, "crate", "yggyg", 2, LetterCase::Insensitive),
+        "Убраны (показано 2 из 3):\n  crate — позиция 1: жёлтая C не может стоять на этом месте\n  \
+        slate — позиция 2: должна быть зелёная R, а стоит L"
+    );
+    assert_eq!(
+        format_removed(&after, &after, "crate", "yggyg", 2, LetterCase::Insensitive),
+        "Ни одно слово не убрано."
+    );
+}
+
+#[test]
+fn preview_lists_the_first_survivors() {
+    let words: Vec<String> = ["crate", "trace", "react"].iter().map(|w| w.to_string()).collect();
+    assert_eq!(format_preview(&words, 2), "Осталось бы 3 слов: crate, trace, …");
+    assert_eq!(format_preview(&words, 3), "Осталось бы 3 слов: crate, trace, react");
+    assert!(format_preview(&[], 3).starts_with("Не осталось бы ни одного слова"));
+}
+
+#[test]
+fn pages_split_long_lists() {
+    let words = ["crate", "trace", "react", "slime", "night"];
+    assert_eq!(page_count(5, 2), 3);
+    assert_eq!(page_count(0, 2), 1);
+    assert_eq!(
+        format_page(&words, 0, 2),
+        "Подходит 5 слов (страница 1 из 3):\ncrate\ntrace\n\
+        Ещё 3 слов: show more — следующая страница, show <номер> — любая, show all — все сразу."
+    );
+    assert_eq!(format_page(&words, 2, 2), "Подходит 5 слов (страница 3 из 3):\nnight");
+    assert_eq!(
+        format_page(&words, 0, 5),
+        "Подходит 5 слов:\ncrate\ntrace\nreact\nslime\nnight"
+    );
+}
+
+#[test]
+fn json_output_includes_suggestions_hint_and_frequencies_when_asked() {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_wordle-solver"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["-g", "crate", "-p", "yggyb", "--format", "json", "--top", "1"])
+        .args([
+            "--suggest",
+            "--hint",
+            "--freq",
+            "--opener-cache",
+            "/nonexistent/opener.json",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["count"], 2);
+    assert_eq!(json["suggestions"].as_array().map(Vec::len), Some(1));
+    assert!(json["suggestions"][0]["entropy"].is_number(), "{}", json);
+    assert!(json["hint"].is_string(), "{}", json);
+    assert_eq!(json["frequencies"]["overall"]["t"], 3);
+
+    let json = serde_json::to_string(&FilterResult::new(&[])).unwrap();
+    assert!(!json.contains("hint"), "{}", json);
+}
@@ -0,0 +1,89 @@
+use wordle_solver::{Fragment, Solver, filter_words, is_anagram, word_regex};
+
+fn words(list: &[&str]) -> Vec<String> {
+    list.iter().map(|w| w.to_string()).collect()
+}
+
+#[test]
+fn regex_is_anchored_to_whole_word() {
+    let regex = word_regex("ight|.ight").unwrap();
+    assert!(regex.is_match("light"));
+    assert!(!regex.is_match("lights"));
+    assert!(!regex.is_match("alight"));
+}
+
+#[test]
+fn invalid_regex_reports_error() {
+    assert!(word_regex("(ab").is_err());
+}
+
+#[test]
+fn retain_composes_with_guesses_and_undoes() {
+    let mut solver = Solver::new(words(&["light", "might", "night", "fight", "crate"]));
+    solver.apply("board", "bbbbb");
+    let regex = word_regex("[mn].*").unwrap();
+    assert_eq!(solver.retain(|word| regex.is_match(word)), 2);
+    assert_eq!(solver.history().len(), 1);
+
+    assert!(solver.undo());
+    assert_eq!(solver.filtered().len(), 4);
+    assert_eq!(solver.history().len(), 1);
+    assert!(solver.undo());
+    assert!(solver.history().is_empty());
+}
+
+#[test]
+fn remove_and_add_edit_only_the_working_list() {
+    let mut solver = Solver::new(words(&["light", "might", "night", "fight", "crate"]));
+    solver.apply("board", "bbbbb");
+    assert_eq!(solver.remove("Might"), Ok(3));
+    assert_eq!(solver.filtered(), words(&["light", "night", "fight"]));
+    assert!(solver.remove("might").is_err());
+    assert!(solver.remove("zzzzz").is_err());
+
+    assert_eq!(solver.add("might"), Ok(4));
+    assert_eq!(solver.filtered(), words(&["light", "might", "night", "fight"]));
+    assert!(solver.add("night").is_err());
+    assert!(solver.add("zzzzz").is_err());
+    assert_eq!(solver.add("crate"), Ok(5));
+
+    assert!(solver.undo());
+    assert!(solver.undo());
+    assert_eq!(solver.filtered(), words(&["light", "night", "fight"]));
+    solver.reset();
+    assert_eq!(solver.filtered().len(), 5);
+    assert_eq!(solver.dictionary().len(), 5);
+}
+
+#[test]
+fn filter_words_parses_dictionary_and_normalizes_pattern() {
+    let dictionary = "Crate\ntrace\nreact\n\ncater\nslate\n";
+    assert_eq!(filter_words(dictionary, "CRANE", "gggbg"), words(&["crate"]));
+    assert_eq!(filter_words(dictionary, "crane", "🟩🟩🟩⬛🟩"), words(&["crate"]));
+    assert_eq!(filter_words(dictionary, "slate", "22222"), words(&["slate"]));
+    assert!(filter_words(dictionary, "crane", "gyg").is_empty());
+}
+
+#[test]
+fn fragments_compose_with_guesses() {
+    assert!(Fragment::Prefix.matches("light", "li"));
+    assert!(!Fragment::Prefix.matches("alight", "li"));
+    assert!(Fragment::Suffix.matches("light", "ght"));
+    assert!(Fragment::Substring.matches("alight", "lig"));
+    assert!(!Fragment::Substring.matches("light", "lgt"));
+
+    let mut solver = Solver::new(words(&["light", "might", "night", "fight", "crate"]));
+    solver.apply("board", "bbbbb");
+    assert_eq!(solver.retain(|word| Fragment::Suffix.matches(word, "ight")), 4);
+    assert_eq!(solver.retain(|word| Fragment::Substring.matches(word, "ni")), 1);
+    assert_eq!(solver.filtered(), ["night"]);
+}
+
+#[test]
+fn anagrams_compare_letter_multisets() {
+    assert!(is_anagram("crate", "taerc"));
+    assert!(is_anagram("crate", "TRACE"));
+    assert!(!is_anagram("crate", "crat"));
+    assert!(!is_anagram("geese", "gesse"));
+    assert!(is_anagram("geese", "eeegs"));
+}
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+use wordle_solver::{DictionaryOptions, Solver, format_stats, parse_frequencies, parse_frequencies_with};
+
+fn words(list: &[&str]) -> Vec<String> {
+    list.iter().map(|w| w.to_string()).collect()
+}
+
+#[test]
+fn parses_and_skips_malformed_lines() {
+    let text = "crate 120\ntrace 7\n\nbroken\nreact abc\nslime 3 extra\nmount 15\n";
+    let (frequencies, malformed) = parse_frequencies(text.as_bytes());
+    assert_eq!(frequencies.len(), 3);
+    assert_eq!(frequencies["crate"], 120);
+    assert_eq!(frequencies["mount"], 15);
+    assert_eq!(malformed, [4, 5, 6]);
+}
+
+#[test]
+fn ranked_orders_by_frequency() {
+    let mut solver = Solver::new(words(&["crate", "trace", "react"]));
+    assert_eq!(solver.ranked(), ["crate", "trace", "react"]);
+
+    solver.set_frequencies(HashMap::from([("react".to_string(), 50), ("trace".to_string(), 10)]));
+    assert_eq!(solver.ranked(), ["react", "trace", "crate"]);
+    assert_eq!(solver.frequency("crate"), 0);
+}
+
+#[test]
+fn frequencies_break_suggestion_ties_but_do_not_change_filtering() {
+    let dictionary = words(&["bat", "cat", "hat"]);
+    let mut plain = Solver::new(dictionary.clone());
+    let mut weighted = Solver::new(dictionary);
+    weighted.set_frequencies(HashMap::from([("hat".to_string(), 9)]));
+
+    assert_eq!(plain.best_guess().unwrap().0, "bat");
+    assert_eq!(weighted.best_guess().unwrap().0, "hat");
+    assert_eq!(weighted.suggest()[0].0, "hat");
+    assert_eq!(plain.apply("bat", "bgg"), weighted.apply("bat", "bgg"));
+    assert_eq!(plain.filtered(), weighted.filtered());
+}
+
+#[test]
+fn stats_counts_common_words_only_with_frequencies() {
+    let mut solver = Solver::new(words(&["crate", "trace", "react", "slime"]));
+    assert_eq!(solver.common_count(), None);
+    assert_eq!(
+        format_stats(&solver),
+        "Подходит 4 слов из 4.\nНеопределённость: 2.0 бит.\n\
+        Чаще всего по позициям: 1: C 1, 2: R 2, 3: A 3, 4: C 2, 5: E 3"
+    );
+
+    solver.set_frequencies(HashMap::from([
+        ("crate".to_string(), 100),
+        ("trace".to_string(), 40),
+        ("react".to_string(), 5),
+        ("slime".to_string(), 1),
+    ]));
+    assert_eq!(solver.common_count(), Some(2));
+    solver.apply("slime", "bbbbg");
+    assert_eq!(solver.common_count(), Some(2));
+    assert!(format_stats(&solver).ends_with("Частых слов: 2 из 2."));
+
+    solver.apply("crate", "bbbbb");
+    assert_eq!(format_stats(&solver), "Подходящих слов нет.");
+}
+
+#[test]
+fn frequency_words_are_normalized_like_dictionary_words() {
+    let text = "\u{feff}Apple 10\nCRATE 3\n";
+    let (frequencies, malformed) = parse_frequencies(text.as_bytes());
+    assert!(malformed.is_empty());
+    assert_eq!(frequencies.get("apple"), Some(&10));
+    assert_eq!(frequencies.get("crate"), Some(&3));
+
+    let keep_case = DictionaryOptions {
+        keep_case: true,
+        ..Default::default()
+    };
+    let (frequencies, _) = parse_frequencies_with(text.as_bytes(), keep_case);
+    assert_eq!(frequencies.get("Apple"), Some(&10));
+}
+
+#[test]
+fn missing_frequency_file_is_reported_in_russian() {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_wordle-solver"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args([
+            "--frequency",
+            "/nonexistent/frequency.txt",
+            "-g",
+            "crate",
+            "-p",
+            "ggggg",
+        ])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("не удалось прочитать файл частот '/nonexistent/frequency.txt'"),
+        "{}",
+        stderr
+    );
+    assert!(!stderr.contains("Os {"), "{}", stderr);
+}
@@ -0,0 +1,103 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use wordle_solver::{Game, Rng, days_from_civil, parse_date, pick_answer};
+
+fn words(list: &[&str]) -> Vec<String> {
+    list.iter().map(|w| w.to_string()).collect()
+}
+
+#[test]
+fn game_reports_patterns_until_won() {
+    let mut game = Game::new("crate");
+    assert_eq!(game.guess("trace"), "yggyg");
+    assert!(!game.is_over());
+    assert_eq!(game.guess("crate"), "ggggg");
+    assert!(game.is_won());
+    assert!(game.is_over());
+    assert_eq!(game.history().len(), 2);
+}
+
+#[test]
+fn game_is_lost_after_six_misses() {
+    let mut game = Game::new("crate");
+    for _ in 0..6 {
+        assert!(!game.is_over());
+        game.guess("slime");
+    }
+    assert!(game.is_over());
+    assert!(!game.is_won());
+}
+
+#[test]
+fn game_ends_after_the_configured_number_of_guesses() {
+    let mut game = Game::new("crate");
+    game.set_max_guesses(2);
+    assert_eq!(game.max_guesses(), 2);
+    game.guess("slime");
+    assert!(!game.is_over());
+    game.guess("slime");
+    assert!(game.is_over());
+    assert!(!game.is_won());
+}
+
+#[test]
+fn answer_is_reproducible() {
+    let answers = words(&["crate", "trace", "react", "slime", "mount"]);
+    assert_eq!(pick_answer(&answers, 42), pick_answer(&answers, 42));
+    assert!(answers.iter().any(|w| Some(w.as_str()) == pick_answer(&answers, 7)));
+    assert_eq!(pick_answer(&[], 1), None);
+}
+
+#[test]
+fn dates_map_to_day_numbers() {
+    assert_eq!(days_from_civil(1970, 1, 1), 0);
+    assert_eq!(parse_date("2021-06-19"), Ok(18_797));
+    assert_eq!(parse_date("2024-02-29"), Ok(19_782));
+    assert!(parse_date("2023-02-29").is_err());
+    assert!(parse_date("2024-13-01").is_err());
+    assert!(parse_date("yesterday").is_err());
+}
+
+#[test]
+fn rng_repeats_for_the_same_seed() {
+    let list = words(&["crate", "trace", "react", "slime", "mound"]);
+    let picks = |seed| {
+        let mut rng = Rng::new(seed);
+        (0..8).map(|_| rng.pick(&list).unwrap().to_string()).collect::<Vec<_>>()
+    };
+    assert_eq!(picks(7), picks(7));
+    assert_ne!(picks(7), picks(8));
+    assert_eq!(Rng::new(7).pick(&list), pick_answer(&list, 7));
+    assert_eq!(Rng::new(7).pick(&[]), None);
+}
+
+#[test]
+fn play_uses_the_max_guesses_limit() {
+    let dir = std::env::temp_dir();
+    let dictionary = dir.join(format!("wordle-solver-play-limit-{}.txt", std::process::id()));
+    let answers = dir.join(format!("wordle-solver-play-limit-answers-{}.txt", std::process::id()));
+    std::fs::write(&dictionary, "crate\nslime\nmount\n").unwrap();
+    std::fs::write(&answers, "crate\n").unwrap();
+    let mut child = Command::new(env!("CARGO_BIN_EXE_wordle-solver"))
+        .args([
+            "-d",
+            dictionary.to_str().unwrap(),
+            "--answers",
+            answers.to_str().unwrap(),
+        ])
+        .args(["--play", "--max-guesses", "2", "--color", "never"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(b"slime\nmount\ncrate\n").unwrap();
+    let output = child.wait_with_output().unwrap();
+    std::fs::remove_file(&dictionary).unwrap();
+    std::fs::remove_file(&answers).unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("У вас 2 попыток"), "{}", stdout);
+    assert!(stdout.contains("Попытка 2/2: "), "{}", stdout);
+    assert!(!stdout.contains("Попытка 3/"), "{}", stdout);
+    assert!(stdout.ends_with("Не угадано. Загаданное слово: crate\n"), "{}", stdout);
+}
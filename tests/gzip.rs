@@ -0,0 +1,22 @@
+use std::path::{Path, PathBuf};
+use wordle_solver::load_dictionary;
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(name)
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn gzipped_dictionary_matches_plain_text() {
+    let plain = load_dictionary(fixture("messy.txt")).unwrap();
+    let gzipped = load_dictionary(fixture("messy.txt.gz")).unwrap();
+    assert_eq!(gzipped.len(), plain.len());
+    assert_eq!(gzipped, plain);
+}
+
+#[cfg(not(feature = "gzip"))]
+#[test]
+fn gzipped_dictionary_needs_the_feature() {
+    let error = load_dictionary(fixture("messy.txt.gz")).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::Unsupported);
+}
@@ -0,0 +1,33 @@
+use wordle_solver::violates_hard_mode;
+
+fn history(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs.iter().map(|(g, p)| (g.to_string(), p.to_string())).collect()
+}
+
+#[test]
+fn moved_green_is_rejected() {
+    let history = history(&[("crate", "bbgbb")]);
+    let reason = violates_hard_mode("salty", &history).unwrap();
+    assert!(reason.contains("3-я буква"));
+    assert!(violates_hard_mode("shard", &history).is_none());
+}
+
+#[test]
+fn missing_yellow_is_rejected() {
+    let history = history(&[("crate", "ybbbb")]);
+    assert!(violates_hard_mode("slimy", &history).unwrap().contains('C'));
+    assert!(violates_hard_mode("music", &history).is_none());
+}
+
+#[test]
+fn repeated_revealed_letter_must_be_reused_twice() {
+    let history = history(&[("eerie", "yybbb")]);
+    assert!(violates_hard_mode("steal", &history).is_some());
+    assert!(violates_hard_mode("sweet", &history).is_none());
+}
+
+#[test]
+fn black_letters_may_be_reused() {
+    assert!(violates_hard_mode("crate", &history(&[("crate", "bbbbb")])).is_none());
+    assert!(violates_hard_mode("crate", &[]).is_none());
+}
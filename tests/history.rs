@@ -0,0 +1,40 @@
+use wordle_solver::{format_history, parse_history, render_colored};
+
+#[test]
+fn parses_pairs_and_skips_empty_lines() {
+    let text = "crate bgbyb\n\n  slime 🟩⬛⬛⬛🟨  \n";
+    let history = parse_history(text.as_bytes(), 5).unwrap();
+    assert_eq!(
+        history,
+        [
+            ("crate".to_string(), "bgbyb".to_string()),
+            ("slime".to_string(), "gbbby".to_string())
+        ]
+    );
+}
+
+#[test]
+fn reports_offending_line_number() {
+    let err = parse_history("crate bgbyb\n\nslime\n".as_bytes(), 5).unwrap_err();
+    assert!(err.starts_with("строка 3:"), "{}", err);
+
+    let err = parse_history("crate bgbyb\nslime bbxbb\n".as_bytes(), 5).unwrap_err();
+    assert!(err.starts_with("строка 2:"), "{}", err);
+
+    let err = parse_history("crates bgbyby\n".as_bytes(), 5).unwrap_err();
+    assert!(err.starts_with("строка 1:"), "{}", err);
+}
+
+#[test]
+fn history_readout_numbers_each_guess() {
+    let history = vec![
+        ("raise".to_string(), "yybbg".to_string()),
+        ("track".to_string(), "yggyb".to_string()),
+    ];
+    assert_eq!(
+        format_history(&history, false),
+        "Догадки (2):\n1. raise yybbg\n2. track yggyb"
+    );
+    assert!(format_history(&history, true).ends_with(&format!("2. {}", render_colored("track", "yggyb"))));
+    assert_eq!(format_history(&[], false), "Догадок пока нет.");
+}
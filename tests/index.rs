@@ -0,0 +1,84 @@
+use std::path::Path;
+use wordle_solver::{Constraints, Solver, WordIndex, load_dictionary, score_guess};
+
+fn words(list: &[&str]) -> Vec<String> {
+    list.iter().map(|w| w.to_string()).collect()
+}
+
+fn dictionary() -> Vec<String> {
+    load_dictionary(Path::new(env!("CARGO_MANIFEST_DIR")).join("wordle-La.txt")).unwrap()
+}
+
+fn brute_force(dictionary: &[String], constraints: &Constraints) -> Vec<usize> {
+    (0..dictionary.len())
+        .filter(|&id| constraints.matches(&dictionary[id]))
+        .collect()
+}
+
+#[test]
+fn candidates_match_brute_force_filter() {
+    let dictionary = dictionary();
+    let index = WordIndex::new(&dictionary);
+    assert_eq!(index.len(), dictionary.len());
+
+    let games = [
+        ("crate", &["raise", "track"][..]),
+        ("eerie", &["geese", "there"][..]),
+        ("llama", &["hello", "label", "allay"][..]),
+        ("abbey", &["babes", "ebbed"][..]),
+        ("sissy", &["missy", "sassy"][..]),
+        ("query", &["zzzzz"][..]),
+    ];
+    for (answer, guesses) in games {
+        let mut constraints = Constraints::new();
+        for guess in guesses {
+            constraints.add(guess, &score_guess(answer, guess));
+            assert_eq!(
+                index.candidates(&constraints),
+                brute_force(&dictionary, &constraints),
+                "{} после {}",
+                answer,
+                guess
+            );
+        }
+    }
+}
+
+#[test]
+fn candidates_follow_direct_constraints() {
+    let dictionary = dictionary();
+    let index = WordIndex::new(&dictionary);
+
+    let mut constraints = Constraints::new();
+    constraints.include('R');
+    constraints.exclude('s');
+    constraints.set_green(0, 'c', 5);
+    constraints.add_yellow(4, 'e', 5);
+    let candidates = index.candidates(&constraints);
+    assert!(!candidates.is_empty());
+    assert_eq!(candidates, brute_force(&dictionary, &constraints));
+}
+
+#[test]
+fn solver_filter_matches_brute_force_after_undo() {
+    let dictionary = dictionary();
+    let mut solver = Solver::new(dictionary.clone());
+    solver.apply("raise", &score_guess("crate", "raise"));
+    solver.apply("track", &score_guess("crate", "track"));
+    assert_eq!(solver.filtered(), words(&["crate"]));
+
+    solver.undo();
+    let constraints = Constraints::from_history(solver.history());
+    let expected: Vec<String> = brute_force(&dictionary, &constraints)
+        .into_iter()
+        .map(|id| dictionary[id].clone())
+        .collect();
+    assert_eq!(solver.filtered(), expected);
+}
+
+#[test]
+fn empty_index_has_no_candidates() {
+    let index = WordIndex::new(&[]);
+    assert!(index.is_empty());
+    assert!(index.candidates(&Constraints::new()).is_empty());
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "mmap")]
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use wordle_solver::{DictionaryOptions, MappedWords, load_dictionary_with};
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(name)
+}
+
+fn run(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_wordle-solver"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn mapped_words_are_normalized_like_loaded_ones() {
+    for options in [
+        DictionaryOptions::default(),
+        DictionaryOptions {
+            keep_case: true,
+            ..DictionaryOptions::default()
+        },
+        DictionaryOptions {
+            allow_nonalpha: true,
+            ..DictionaryOptions::default()
+        },
+    ] {
+        let (words, rejected) = load_dictionary_with(fixture("messy.txt"), options).unwrap();
+        let mapped = MappedWords::open(fixture("messy.txt"), options).unwrap();
+        assert_eq!(mapped.iter().collect::<Vec<_>>(), words);
+        assert_eq!(mapped.rejected(), rejected);
+    }
+}
+
+#[test]
+fn mmap_filter_matches_regular_filter() {
+    let args = ["--format", "plain", "-g", "crate", "-p", "bybbb", "--exclude", "s"];
+    let mut regular = run(&args).lines().map(str::to_string).collect::<Vec<_>>();
+    let mut mapped = run(&[&args[..], &["--mmap"]].concat())
+        .lines()
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    assert!(!regular.is_empty());
+    regular.sort();
+    mapped.sort();
+    assert_eq!(mapped, regular);
+
+    let count = run(&["--mmap", "--count", "-g", "crate", "-p", "bybbb"]);
+    assert_eq!(count, run(&["--count", "-g", "crate", "-p", "bybbb"]));
+}
@@ -0,0 +1,180 @@
+use wordle_solver::{Constraints, Solver, format_check, match_reason, matches_pattern};
+
+#[test]
+fn green_yellow_black_match() {
+    assert!(matches_pattern("trace", "crate", "yggyg"));
+    assert!(matches_pattern("crate", "crate", "ggggg"));
+    assert!(!matches_pattern("crate", "crate", "bgggg"));
+    assert!(!matches_pattern("slime", "crate", "yggyg"));
+}
+
+#[test]
+fn length_mismatch_never_matches() {
+    assert!(!matches_pattern("crate", "crat", "gggg"));
+    assert!(!matches_pattern("crate", "crate", "gggg"));
+}
+
+#[test]
+fn solver_filters_and_resets() {
+    let dictionary: Vec<String> = ["crate", "trace", "react", "slime"]
+        .iter()
+        .map(|w| w.to_string())
+        .collect();
+    let mut solver = Solver::new(dictionary);
+
+    assert_eq!(solver.apply("crate", "bbgbb"), 0);
+    solver.reset();
+    assert_eq!(solver.apply("crate", "yyyyy"), 0);
+    solver.reset();
+    assert_eq!(solver.apply("crate", "yygyy"), 1);
+    assert_eq!(solver.filtered(), ["react"]);
+
+    solver.reset();
+    assert_eq!(solver.filtered().len(), 4);
+}
+
+#[test]
+fn solver_undo_restores_previous_states() {
+    let dictionary: Vec<String> = ["crate", "trace", "react", "slime"]
+        .iter()
+        .map(|w| w.to_string())
+        .collect();
+    let mut solver = Solver::new(dictionary);
+
+    assert!(!solver.undo());
+    solver.apply("slime", "bbbbg");
+    solver.apply("crate", "ggggg");
+    assert_eq!(solver.filtered(), ["crate"]);
+
+    assert!(solver.undo());
+    assert_eq!(solver.filtered(), ["crate", "trace"]);
+    assert_eq!(solver.history().len(), 1);
+
+    assert!(solver.undo());
+    assert_eq!(solver.filtered().len(), 4);
+    assert!(solver.constraints().is_empty());
+    assert!(!solver.undo());
+}
+
+#[test]
+fn solver_redo_reapplies_undone_steps_until_a_new_guess() {
+    let dictionary: Vec<String> = ["crate", "trace", "react", "slime"]
+        .iter()
+        .map(|w| w.to_string())
+        .collect();
+    let mut solver = Solver::new(dictionary);
+
+    assert!(!solver.redo());
+    solver.apply("slime", "bbbbg");
+    solver.exclude("r");
+    solver.apply("crate", "ggggg");
+    assert!(solver.undo());
+    assert!(solver.undo());
+    assert!(solver.undo());
+    assert_eq!(solver.filtered().len(), 4);
+
+    assert!(solver.redo());
+    assert_eq!(solver.filtered(), ["crate", "trace"]);
+    assert_eq!(solver.history().len(), 1);
+    assert!(solver.redo());
+    assert!(solver.filtered().is_empty());
+    assert!(solver.redo());
+    assert_eq!(solver.history().len(), 2);
+    assert!(!solver.redo());
+
+    assert!(solver.undo());
+    assert!(solver.undo());
+    assert!(solver.undo());
+    assert!(solver.redo());
+    solver.apply("trace", "ggggg");
+    assert!(!solver.redo());
+    assert_eq!(solver.filtered(), ["trace"]);
+    assert!(solver.undo());
+    assert_eq!(solver.filtered(), ["crate", "trace"]);
+
+    assert!(solver.undo());
+    solver.reset();
+    assert!(!solver.redo());
+}
+
+#[test]
+fn solver_filters_answers_and_suggests_from_guesses() {
+    let guesses: Vec<String> = ["crate", "trace", "slime", "mound"]
+        .iter()
+        .map(|w| w.to_string())
+        .collect();
+    let answers: Vec<String> = ["crate", "trace", "react"].iter().map(|w| w.to_string()).collect();
+    let mut solver = Solver::with_answers(guesses, answers);
+
+    assert_eq!(solver.dictionary(), ["crate", "trace", "slime", "mound", "react"]);
+    assert_eq!(solver.apply("slime", "bbbbg"), 2);
+    assert_eq!(solver.filtered(), ["crate", "trace"]);
+    assert_eq!(solver.suggest().len(), 5);
+
+    solver.reset();
+    assert_eq!(solver.filtered(), ["crate", "trace", "react"]);
+}
+
+#[test]
+fn match_reason_names_the_first_broken_rule() {
+    assert_eq!(match_reason("trace", "crate", "yggyg"), Ok(()));
+    assert_eq!(
+        match_reason("slate", "crate", "bgbbb"),
+        Err("позиция 2: должна быть зелёная R, а стоит L".to_string())
+    );
+    assert_eq!(
+        match_reason("crate", "crate", "bgggg"),
+        Err("позиция 1: чёрная C не может стоять на этом месте".to_string())
+    );
+    assert_eq!(
+        match_reason("brine", "crate", "bgybb"),
+        Err("нет обязательной буквы A".to_string())
+    );
+    assert_eq!(
+        match_reason("arcad", "crate", "bgybb"),
+        Err("есть исключённая буква C".to_string())
+    );
+    assert!(match_reason("crat", "crate", "ggggg").unwrap_err().contains("4 букв"));
+}
+
+#[test]
+fn constraints_reason_covers_commands() {
+    let mut constraints = Constraints::from_history(&[("crate".to_string(), "bgybb".to_string())]);
+    constraints.exclude('s');
+    assert_eq!(
+        constraints.match_reason("draws"),
+        Err("позиция 3: буквы A здесь быть не может".to_string())
+    );
+    assert_eq!(
+        constraints.match_reason("proas"),
+        Err("есть исключённая буква S".to_string())
+    );
+    assert_eq!(constraints.match_reason("broad"), Ok(()));
+}
+
+#[test]
+fn check_answers_yes_or_names_the_guess_that_rules_the_word_out() {
+    let history = [
+        ("slime".to_string(), "bbbbg".to_string()),
+        ("crate".to_string(), "bgbbg".to_string()),
+    ];
+    let mut constraints = Constraints::from_history(&history);
+    assert_eq!(
+        format_check("qrzze", &history, &constraints),
+        "qrzze: да, подходит под все догадки (2)."
+    );
+    assert_eq!(
+        format_check("smoke", &history, &constraints),
+        "smoke: нет — догадка 1 (slime bbbbg): позиция 1: чёрная S не может стоять на этом месте"
+    );
+    assert_eq!(
+        format_check("brake", &history, &constraints),
+        "brake: нет — догадка 2 (crate bgbbg): позиция 3: чёрная A не может стоять на этом месте"
+    );
+    constraints.exclude('q');
+    assert_eq!(
+        format_check("qrzze", &history, &constraints),
+        "qrzze: нет — есть исключённая буква Q"
+    );
+    assert!(format_check("qrzze", &[], &Constraints::default()).contains("подходит любое слово"));
+}
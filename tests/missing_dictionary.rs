@@ -0,0 +1,57 @@
+use std::process::{Command, Output};
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_wordle-solver"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn missing_dictionary_explains_what_to_do() {
+    let output = run(&["-d", "no-such-words.txt", "-g", "crate", "-p", "bbbbb"]);
+    assert_eq!(output.status.code(), Some(3));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("файл словаря 'no-such-words.txt' не найден"),
+        "{}",
+        stderr
+    );
+    assert!(stderr.contains("--dictionary"), "{}", stderr);
+    assert!(stderr.contains("--list-embedded"), "{}", stderr);
+}
+
+#[test]
+fn missing_answers_file_names_its_flag() {
+    let output = run(&["-a", "no-such-answers.txt", "-g", "crate", "-p", "bbbbb"]);
+    assert_eq!(output.status.code(), Some(3));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("'no-such-answers.txt'"), "{}", stderr);
+    assert!(stderr.contains("--answers"), "{}", stderr);
+}
+
+#[test]
+fn unreadable_path_gets_its_own_message() {
+    let output = run(&["-d", "tests", "-g", "crate", "-p", "bbbbb"]);
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("не удалось прочитать словарь 'tests'"), "{}", stderr);
+}
+
+#[test]
+fn missing_file_among_several_dictionaries_is_named() {
+    let output = run(&[
+        "-d",
+        "tests/fixtures/messy.txt",
+        "-d",
+        "no-such-extra.txt",
+        "-g",
+        "crate",
+        "-p",
+        "bbbbb",
+    ]);
+    assert_eq!(output.status.code(), Some(3));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("'no-such-extra.txt'"), "{}", stderr);
+}
@@ -0,0 +1,74 @@
+use wordle_solver::{Opener, OpenerCache, Solver, Strategy, dictionary_hash};
+
+fn words(list: &[&str]) -> Vec<String> {
+    list.iter().map(|w| w.to_string()).collect()
+}
+
+fn solver() -> Solver {
+    Solver::new(words(&["crate", "trace", "slate", "plate", "crane", "brine"]))
+}
+
+#[test]
+fn cached_opener_matches_fresh_computation() {
+    let solver = solver();
+    let opener = Opener::compute(&solver, Strategy::Entropy, 3).unwrap();
+    assert_eq!(Some(opener.best.word.clone()), solver.best_guess_by(Strategy::Entropy));
+    assert_eq!(opener.top, solver.suggestions(Strategy::Entropy, 3));
+
+    let mut cache = OpenerCache::default();
+    cache.insert(&solver, Strategy::Entropy, opener.clone());
+    assert_eq!(cache.get(&solver, Strategy::Entropy), Some(&opener));
+    assert_eq!(cache.get(&solver, Strategy::Minimax), None);
+}
+
+#[test]
+fn cache_is_ignored_after_guesses_or_dictionary_changes() {
+    let mut solver = solver();
+    let mut cache = OpenerCache::default();
+    cache.insert(
+        &solver,
+        Strategy::Minimax,
+        Opener::compute(&solver, Strategy::Minimax, 10).unwrap(),
+    );
+
+    solver.apply("crate", "bbbbb");
+    assert_eq!(cache.get(&solver, Strategy::Minimax), None);
+    solver.reset();
+    assert!(cache.get(&solver, Strategy::Minimax).is_some());
+
+    let other = Solver::new(words(&["crate", "trace", "slate", "plate", "crane", "brink"]));
+    assert_ne!(dictionary_hash(&other), dictionary_hash(&solver));
+    assert_eq!(cache.get(&other, Strategy::Minimax), None);
+    assert_eq!(Opener::compute(&Solver::new(Vec::new()), Strategy::Entropy, 10), None);
+}
+
+#[test]
+fn cache_round_trips_through_file() {
+    let path = std::env::temp_dir().join(format!("wordle-solver-opener-{}.json", std::process::id()));
+    assert_eq!(OpenerCache::load(&path).unwrap(), OpenerCache::default());
+
+    let solver = solver();
+    let mut cache = OpenerCache::default();
+    cache.insert(
+        &solver,
+        Strategy::Entropy,
+        Opener::compute(&solver, Strategy::Entropy, 10).unwrap(),
+    );
+    cache.save(&path).unwrap();
+    let loaded = OpenerCache::load(&path).unwrap();
+    let (saved, restored) = (
+        cache.get(&solver, Strategy::Entropy).unwrap(),
+        loaded.get(&solver, Strategy::Entropy).unwrap(),
+    );
+    assert_eq!(restored.best.word, saved.best.word);
+    let words = |opener: &Opener| {
+        opener
+            .top
+            .iter()
+            .map(|s| (s.word.clone(), s.worst_case))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(words(restored), words(saved));
+    assert!((restored.best.entropy - saved.best.entropy).abs() < 1e-12);
+    std::fs::remove_file(&path).unwrap();
+}
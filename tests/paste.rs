@@ -0,0 +1,218 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+fn interactive(input: &str) -> String {
+    interactive_with(&[], input)
+}
+
+fn interactive_with(args: &[&str], input: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_wordle-solver"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .arg("-i")
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    String::from_utf8(child.wait_with_output().unwrap().stdout).unwrap()
+}
+
+#[test]
+fn paste_applies_emoji_rows_at_once() {
+    let stdout = interactive("paste\nraise 🟨🟨⬛⬛🟩\ntrack ⬛🟩🟩🟨⬛\n\nshow\nexit\n");
+    assert!(stdout.contains("Применено догадок: 2. Подходит 3 слов."), "{}", stdout);
+}
+
+#[test]
+fn paste_with_bad_row_applies_nothing() {
+    let stdout = interactive("paste\nraise 🟨🟨⬛⬛🟩\ntrack ⬛🟩\n\nexit\n");
+    assert!(stdout.contains("Ошибка: строка 2:"), "{}", stdout);
+    assert!(stdout.contains("ничего не применено"), "{}", stdout);
+    assert!(!stdout.contains("Применено догадок"), "{}", stdout);
+}
+
+#[test]
+fn interactive_starts_from_batch_guesses() {
+    let stdout = interactive_with(
+        &["-g", "raise", "-p", "yybbg", "-g", "track", "-p", "yggyb"],
+        "show\nexit\n",
+    );
+    assert!(stdout.contains("Применено догадок из --guess/--pattern: 2. Подходит 1 слов."));
+    assert!(stdout.contains("Подходит 1 слов:\ncrate"), "{}", stdout);
+}
+
+#[test]
+fn try_previews_a_guess_without_applying_it() {
+    let stdout = interactive("try crate ggggg\ntry crate bbbbq\nshow\nexit\n");
+    assert!(stdout.contains("Осталось бы 1 слов: crate"), "{}", stdout);
+    assert!(stdout.contains("Ошибка: недопустимый символ 'q'"), "{}", stdout);
+    assert!(stdout.contains("Подходит 2315 слов"), "{}", stdout);
+}
+
+#[test]
+fn show_pages_through_long_lists() {
+    let stdout = interactive("show\nshow more\nshow 47\nshow more\nshow 48\nexit\n");
+    assert!(
+        stdout.contains("Подходит 2315 слов (страница 1 из 47):\naback\n"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("(страница 2 из 47)"), "{}", stdout);
+    assert!(stdout.contains("(страница 47 из 47)"), "{}", stdout);
+    assert!(stdout.contains("Ошибка: это последняя страница"), "{}", stdout);
+    assert!(
+        stdout.contains("Ошибка: страницы 48 нет: всего страниц 47"),
+        "{}",
+        stdout
+    );
+}
+
+#[test]
+fn anagram_lists_matching_candidates_without_filtering() {
+    let stdout = interactive("anagram TAERC\nanagram zzzzz\nshow\nexit\n");
+    assert!(
+        stdout.contains("Анаграммы TAERC (4): cater, crate, react, trace"),
+        "{}",
+        stdout
+    );
+    assert!(
+        stdout.contains("Среди подходящих слов нет анаграмм ZZZZZ."),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("Подходит 2315 слов"), "{}", stdout);
+}
+
+#[test]
+fn wrong_length_guess_leaves_the_list_untouched() {
+    let stdout = interactive("crates bbbbbb\nshow\nexit\n");
+    assert!(
+        stdout.contains("Ошибка: догадка должна состоять из 5 букв, а в 'crates' их 6"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("Подходит 2315 слов"), "{}", stdout);
+}
+
+#[test]
+fn history_lists_guesses_and_follows_undo() {
+    let stdout = interactive_with(
+        &["--color", "never"],
+        "history\nraise yybbg\ntrack yggyb\nhistory\nundo\nhistory\nexit\n",
+    );
+    assert!(stdout.contains("Догадок пока нет."), "{}", stdout);
+    assert!(
+        stdout.contains("Догадки (2):\n1. raise yybbg\n2. track yggyb\n"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("Догадки (1):\n1. raise yybbg\n"), "{}", stdout);
+}
+
+#[test]
+fn expected_compares_several_guesses() {
+    let stdout = interactive("raise yybbg\nexpected track qqqqq crates\nexit\n");
+    assert!(
+        stdout.contains("track: в среднем останется 3.69 из 26 слов"),
+        "{}",
+        stdout
+    );
+    assert!(
+        stdout.contains("qqqqq: в среднем останется 26.00 из 26 слов"),
+        "{}",
+        stdout
+    );
+    assert!(
+        stdout.contains("Ошибка: догадка должна состоять из 5 букв, а в 'crates' их 6"),
+        "{}",
+        stdout
+    );
+}
+
+#[test]
+fn pick_plays_a_numbered_suggestion() {
+    let stdout = interactive("pick 1\nraise yybbg\nsuggest\npick 11\npick 1\nyggyb\npick 1\nexit\n");
+    assert!(
+        stdout.contains("Ошибка: выбирать не из чего: сначала выполните suggest"),
+        "{}",
+        stdout
+    );
+    assert!(
+        stdout.contains("Ошибка: номер догадки должен быть от 1 до 10, а не '11'"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("Догадка: track"), "{}", stdout);
+    assert!(stdout.contains("Убрано 25 слов, осталось 1."), "{}", stdout);
+    assert_eq!(stdout.matches("Ошибка: выбирать не из чего").count(), 2, "{}", stdout);
+}
+
+#[test]
+fn long_candidate_lists_fall_back_to_letter_coverage() {
+    let cache = std::env::temp_dir().join(format!("wordle-solver-no-opener-{}.json", std::process::id()));
+    let args = [
+        "--max-candidates-for-suggest",
+        "100",
+        "--opener-cache",
+        cache.to_str().unwrap(),
+    ];
+    let stdout = interactive_with(&args, "suggest\npick 1\nbbbbb\nhint\nslime bbbbb\nsuggest\nexit\n");
+    assert!(
+        stdout.contains("Лучшие догадки по охвату букв (гласная — 2 очка, согласная — 1):"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("Подсказка: "), "{}", stdout);
+    assert!(stdout.contains("охват букв"), "{}", stdout);
+    assert!(stdout.contains("Лучшие догадки:\n"), "{}", stdout);
+}
+
+#[test]
+fn replay_continues_in_interactive_mode() {
+    let stdout = interactive_with(&["--replay", "tests/fixtures/replay.txt"], "undo\nshow\nexit\n");
+    assert!(stdout.contains("Шаг 1: raise yybbg — осталось 26 слов"), "{}", stdout);
+    assert!(
+        stdout.contains("Повторено догадок из --replay: 2. Подходит 1 слов."),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("Подходит 26 слов"), "{}", stdout);
+}
+
+#[test]
+fn green_can_be_played_as_a_guess() {
+    let stdout = interactive("green ybbbg\nshow\nexit\n");
+    assert!(!stdout.contains("Ошибка"), "{}", stdout);
+    assert!(stdout.contains("Убрано "), "{}", stdout);
+}
+
+#[test]
+fn guess_prefix_plays_a_word_that_names_a_command() {
+    let stdout = interactive("guess known\nbbbbb\nhistory\nexit\n");
+    assert!(stdout.contains("Введите pattern"), "{}", stdout);
+    assert!(stdout.contains("1. known bbbbb"), "{}", stdout);
+}
+
+#[test]
+fn paste_checks_hard_mode_and_strict_guess_before_applying() {
+    let stdout = interactive_with(&["--hard"], "paste\nraise yybbg\nbotch bbbbb\n\nshow\nexit\n");
+    assert!(stdout.contains("Ошибка: строка 2: сложный режим:"), "{}", stdout);
+    assert!(stdout.contains("ничего не применено"), "{}", stdout);
+    assert!(stdout.contains("Подходит 2315 слов"), "{}", stdout);
+
+    let stdout = interactive_with(&["--strict-guess"], "paste\nqqqqq bbbbb\n\nexit\n");
+    assert!(stdout.contains("Ошибка: строка 1: слова 'qqqqq' нет"), "{}", stdout);
+    assert!(!stdout.contains("Применено догадок"), "{}", stdout);
+}
+
+#[test]
+fn pick_rejects_suggestions_for_an_old_word_list() {
+    let stdout = interactive("raise yybbg\nsuggest\nundo\npick 1\nredo\npick 1\nbbbbb\nexit\n");
+    assert!(
+        stdout.contains("Ошибка: список подходящих слов изменился после suggest: выполните suggest снова"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("Догадка: "), "{}", stdout);
+}
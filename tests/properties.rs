@@ -0,0 +1,53 @@
+use proptest::prelude::*;
+use wordle_solver::{Constraints, match_reason, matches_pattern, score_guess, validate_pattern};
+
+/// Слово из маленького алфавита, чтобы повторы букв встречались часто.
+fn word(length: usize) -> impl Strategy<Value = String> {
+    proptest::collection::vec(prop::sample::select(vec!['a', 'b', 'c', 'd']), length)
+        .prop_map(|letters| letters.into_iter().collect())
+}
+
+/// Догадка, слово и произвольный (возможно, противоречивый) шаблон одной длины.
+fn with_pattern() -> impl Strategy<Value = (String, String, String)> {
+    (1usize..=6).prop_flat_map(|length| {
+        let pattern = proptest::collection::vec(prop::sample::select(vec!['g', 'y', 'b']), length)
+            .prop_map(|colors| colors.into_iter().collect());
+        (word(length), word(length), pattern)
+    })
+}
+
+/// Догадка, ответ и ещё одно слово одной длины.
+fn triple() -> impl Strategy<Value = (String, String, String)> {
+    (1usize..=6).prop_flat_map(|length| (word(length), word(length), word(length)))
+}
+
+proptest! {
+    #[test]
+    fn answer_matches_its_own_pattern((answer, guess, _) in triple()) {
+        let pattern = score_guess(&answer, &guess);
+        prop_assert!(matches_pattern(&answer, &guess, &pattern));
+    }
+
+    #[test]
+    fn real_patterns_pass_validation((answer, guess, _) in triple()) {
+        let pattern = score_guess(&answer, &guess);
+        prop_assert_eq!(validate_pattern(&guess, &pattern), Ok(()));
+    }
+
+    #[test]
+    fn word_matches_exactly_when_it_gives_the_same_pattern((answer, guess, word) in triple()) {
+        let pattern = score_guess(&answer, &guess);
+        prop_assert_eq!(matches_pattern(&word, &guess, &pattern), score_guess(&word, &guess) == pattern);
+    }
+
+    #[test]
+    fn match_reason_agrees_with_matches_pattern((guess, word, pattern) in with_pattern()) {
+        prop_assert_eq!(match_reason(&word, &guess, &pattern).is_ok(), matches_pattern(&word, &guess, &pattern));
+    }
+
+    #[test]
+    fn constraints_reason_agrees_with_matches((guess, word, pattern) in with_pattern()) {
+        let constraints = Constraints::from_history(&[(guess, pattern)]);
+        prop_assert_eq!(constraints.match_reason(&word).is_ok(), constraints.matches(&word));
+    }
+}
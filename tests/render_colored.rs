@@ -0,0 +1,14 @@
+use wordle_solver::render_colored;
+
+#[test]
+fn each_letter_gets_its_color() {
+    let rendered = render_colored("crate", "gybbg");
+    assert!(rendered.starts_with("\x1b[30;42m C \x1b[0m\x1b[30;43m R \x1b[0m"));
+    assert_eq!(rendered.matches("\x1b[30;42m").count(), 2);
+    assert_eq!(rendered.matches("\x1b[2;37m").count(), 2);
+}
+
+#[test]
+fn pattern_case_is_ignored() {
+    assert_eq!(render_colored("crate", "GYBBG"), render_colored("crate", "gybbg"));
+}
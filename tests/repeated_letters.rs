@@ -0,0 +1,35 @@
+use wordle_solver::{matches_pattern, score_guess};
+
+#[test]
+fn black_copy_forbids_its_own_position() {
+    // В "hovel" буква e стоит на месте чёрной копии: Wordle показал бы её зелёной.
+    assert!(!matches_pattern("hovel", "speed", "bbybb"));
+    assert!(matches_pattern("olive", "speed", "bbybb"));
+}
+
+#[test]
+fn black_copy_caps_letter_count() {
+    assert!(!matches_pattern("elate", "speed", "bbybb"));
+    assert!(!matches_pattern("elate", "speed", "bbbyb"));
+}
+
+#[test]
+fn yellow_and_black_order_does_not_matter() {
+    assert!(matches_pattern("olive", "speed", "bbbyb"));
+    assert!(matches_pattern("abide", "speed", "bbyby"));
+    assert!(matches_pattern("abide", "speed", "bbbyy"));
+}
+
+#[test]
+fn two_yellow_copies_require_two_letters() {
+    assert!(matches_pattern("sweet", "eerie", "yybbb"));
+    assert!(!matches_pattern("steal", "eerie", "yybbb"));
+    assert!(!matches_pattern("sweet", "eerie", "yybby"));
+}
+
+#[test]
+fn green_plus_black_copy_means_exactly_one() {
+    assert_eq!(score_guess("crane", "eerie"), "bbybg");
+    assert!(matches_pattern("crane", "eerie", "bbybg"));
+    assert!(!matches_pattern("erase", "eerie", "bbybg"));
+}
@@ -0,0 +1,90 @@
+use wordle_solver::{Solver, Strategy, format_report, score_guess};
+
+fn words(list: &[&str]) -> Vec<String> {
+    list.iter().map(|w| w.to_string()).collect()
+}
+
+#[test]
+fn report_has_a_row_per_turn_and_the_answer() {
+    let mut solver = Solver::new(words(&["crate", "grate", "slate", "plate", "crane"]));
+    for guess in ["slate", "crane", "crate"] {
+        solver.apply(guess, &score_guess("crate", guess));
+    }
+    let report = format_report(&solver, Strategy::Entropy, 0);
+    let lines: Vec<&str> = report.lines().collect();
+    assert_eq!(lines[0], "# Решение Wordle");
+    assert!(lines[2].starts_with("| # | Догадка | Шаблон |"));
+    assert!(lines[3].starts_with("|---|"));
+    assert!(
+        lines[4].starts_with("| 1 | slate | ⬛⬛🟩🟩🟩 `bbggg` | 2 | "),
+        "{}",
+        report
+    );
+    assert!(
+        lines[6].starts_with("| 3 | crate | 🟩🟩🟩🟩🟩 `ggggg` | 1 | "),
+        "{}",
+        report
+    );
+    assert!(
+        lines
+            .iter()
+            .all(|line| !line.starts_with('|') || line.matches('|').count() == 6)
+    );
+    assert!(
+        report.ends_with("**Ответ:** crate, угадано за 3 догадок.\n"),
+        "{}",
+        report
+    );
+}
+
+#[test]
+fn report_without_the_answer_says_how_many_words_remain() {
+    let mut solver = Solver::new(words(&["crate", "grate", "slate", "plate", "crane"]));
+    solver.apply("slate", "bbggg");
+    assert!(
+        format_report(&solver, Strategy::Minimax, 0).contains("**Ответ не найден:** после 1 догадок подходит 2 слов.")
+    );
+    solver.apply("crane", "gggbg");
+    assert!(
+        format_report(&solver, Strategy::Entropy, 0)
+            .contains("**Ответ:** crate (найден после 2 догадок, ещё не введён).")
+    );
+}
+
+#[test]
+fn report_over_the_candidate_limit_ranks_by_letter_coverage() {
+    let mut solver = Solver::new(words(&["crate", "grate", "slate", "plate", "crane"]));
+    for guess in ["slate", "crane"] {
+        solver.apply(guess, &score_guess("crate", guess));
+    }
+    let report = format_report(&solver, Strategy::Entropy, 3);
+    let lines: Vec<&str> = report.lines().collect();
+    assert!(lines[4].ends_with("| crate (охват букв 7) |"), "{}", report);
+    assert!(lines[5].ends_with("бит) |"), "{}", report);
+}
+
+#[test]
+fn report_counts_follow_filters_applied_between_guesses() {
+    let mut solver = Solver::new(words(&["crate", "grate", "slate", "plate", "crane"]));
+    solver.exclude("p");
+    solver.apply("slate", &score_guess("crate", "slate"));
+    solver.remove("grate").unwrap();
+    solver.apply("crane", &score_guess("crate", "crane"));
+    let report = format_report(&solver, Strategy::Entropy, 0);
+    let lines: Vec<&str> = report.lines().collect();
+    assert!(
+        lines[4].starts_with("| 1 | slate | ⬛⬛🟩🟩🟩 `bbggg` | 2 | "),
+        "{}",
+        report
+    );
+    assert!(
+        lines[5].starts_with("| 2 | crane | 🟩🟩🟩⬛🟩 `gggbg` | 1 | "),
+        "{}",
+        report
+    );
+    assert!(
+        report.ends_with("**Ответ:** crate (найден после 2 догадок, ещё не введён).\n"),
+        "{}",
+        report
+    );
+}
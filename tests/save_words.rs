@@ -0,0 +1,21 @@
+use wordle_solver::{load_dictionary, save_words};
+
+fn words(list: &[&str]) -> Vec<String> {
+    list.iter().map(|w| w.to_string()).collect()
+}
+
+#[test]
+fn saved_words_load_back() {
+    let path = std::env::temp_dir().join(format!("wordle-solver-save-{}.txt", std::process::id()));
+    let list = words(&["crate", "trace", "react"]);
+    save_words(&path, &list).unwrap();
+    let loaded = load_dictionary(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.unwrap(), list);
+}
+
+#[test]
+fn unwritable_path_is_an_error() {
+    let path = std::env::temp_dir().join("wordle-solver-missing-dir").join("words.txt");
+    assert!(save_words(path, &words(&["crate"])).is_err());
+}
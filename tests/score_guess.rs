@@ -0,0 +1,60 @@
+use wordle_solver::{
+    EncodedWord, LetterCase, MAX_CODED_LEN, matches_pattern, score_encoded, score_guess, score_guess_with,
+};
+
+#[test]
+fn exact_and_disjoint_guesses() {
+    assert_eq!(score_guess("crate", "crate"), "ggggg");
+    assert_eq!(score_guess("crate", "shown"), "bbbbb");
+    assert_eq!(score_guess("trace", "crate"), "yggyg");
+}
+
+#[test]
+fn duplicate_letters_in_answer() {
+    assert_eq!(score_guess("allee", "eagle"), "yybyg");
+}
+
+#[test]
+fn extra_copies_in_guess_become_black() {
+    assert_eq!(score_guess("abbey", "keeps"), "bybbb");
+    assert_eq!(score_guess("sweet", "eerie"), "yybbb");
+    assert_eq!(score_guess("crane", "eerie"), "bbybg");
+}
+
+#[test]
+fn score_is_accepted_by_matches_pattern() {
+    for (answer, guess) in [
+        ("allee", "eagle"),
+        ("abbey", "keeps"),
+        ("sweet", "eerie"),
+        ("trace", "crate"),
+    ] {
+        assert!(matches_pattern(answer, guess, &score_guess(answer, guess)));
+    }
+}
+
+#[test]
+fn words_longer_than_the_stack_buffer_score_and_match_the_same_way() {
+    let answer = format!("{}ab", "x".repeat(MAX_CODED_LEN));
+    let guess = format!("{}ba", "x".repeat(MAX_CODED_LEN));
+    let pattern = format!("{}yy", "g".repeat(MAX_CODED_LEN));
+    assert_eq!(score_guess(&answer, &guess), pattern);
+    assert!(matches_pattern(&answer, &guess, &pattern));
+    assert!(!matches_pattern(&guess, &guess, &pattern));
+}
+
+#[test]
+fn encoded_and_string_scores_agree() {
+    for (answer, guess) in [("allee", "eagle"), ("abbey", "keeps"), ("Crate", "cRATE")] {
+        for case in [LetterCase::Insensitive, LetterCase::Sensitive] {
+            assert_eq!(
+                score_encoded(
+                    &EncodedWord::with_case(answer, case),
+                    &EncodedWord::with_case(guess, case)
+                ),
+                score_guess_with(answer, guess, case)
+            );
+        }
+    }
+    assert_eq!(score_guess_with("Crate", "crate", LetterCase::Sensitive), "bgggg");
+}
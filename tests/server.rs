@@ -0,0 +1,98 @@
+#![cfg(feature = "server")]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::process::{Child, Command, Stdio};
+
+/// Сервер на свободном порту; завершается вместе с тестом.
+struct Server {
+    child: Child,
+    port: u16,
+}
+
+impl Server {
+    fn start(args: &[&str]) -> Self {
+        let port = TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+        let mut child = Command::new(env!("CARGO_BIN_EXE_wordle-solver"))
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .args(["--serve", "--port", &port.to_string()])
+            .args(args)
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+        let mut stderr = BufReader::new(child.stderr.take().unwrap());
+        let mut line = String::new();
+        stderr.read_line(&mut line).unwrap();
+        assert!(line.contains("Сервер слушает"), "{}", line);
+        Server { child, port }
+    }
+
+    /// POST с JSON-телом; возвращает код ответа и тело.
+    fn post(&self, path: &str, body: &str) -> (u16, String) {
+        let mut stream = TcpStream::connect(("127.0.0.1", self.port)).unwrap();
+        write!(
+            stream,
+            "POST {} HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            path,
+            body.len(),
+            body
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let status = response[9..12].parse().unwrap();
+        let body = response.split_once("\r\n\r\n").unwrap().1.to_string();
+        (status, body)
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[test]
+fn filter_and_suggest_over_http() {
+    let server = Server::start(&[]);
+
+    let (status, body) = server.post(
+        "/filter",
+        r#"{"guesses": [{"guess": "raise", "pattern": "yybbg"}, {"guess": "track", "pattern": "yggyb"}]}"#,
+    );
+    assert_eq!(status, 200);
+    assert_eq!(body, r#"{"count":1,"words":["crate"]}"#);
+
+    let (status, body) = server.post(
+        "/filter",
+        r#"{"dictionary": ["crate", "Trace", "slime"], "guesses": [{"guess": "crate", "pattern": "🟨🟩🟩🟨🟩"}]}"#,
+    );
+    assert_eq!(status, 200);
+    assert_eq!(body, r#"{"count":1,"words":["trace"]}"#);
+
+    let (status, body) = server.post(
+        "/suggest",
+        r#"{"dictionary": ["crate", "trace", "slime"], "top": 1, "strategy": "minimax"}"#,
+    );
+    assert_eq!(status, 200);
+    assert!(body.starts_with(r#"[{"word":"#), "{}", body);
+    assert!(body.contains(r#""worst_case":1"#), "{}", body);
+
+    let (status, body) = server.post("/filter", r#"{"guesses": [{"guess": "crate", "pattern": "gg"}]}"#);
+    assert_eq!(status, 400);
+    assert!(body.contains("error"), "{}", body);
+}
+
+#[test]
+fn suggest_over_the_candidate_limit_ranks_by_letter_coverage() {
+    let server = Server::start(&["--max-candidates-for-suggest", "2"]);
+
+    let (status, body) = server.post("/suggest", r#"{"dictionary": ["crate", "trace", "slime"], "top": 1}"#);
+    assert_eq!(status, 200);
+    assert_eq!(body, r#"[{"word":"crate","coverage":7}]"#);
+
+    let (status, body) = server.post("/suggest", r#"{"dictionary": ["crate", "trace"], "top": 1}"#);
+    assert_eq!(status, 200);
+    assert!(body.contains(r#""entropy":"#), "{}", body);
+}
@@ -0,0 +1,77 @@
+use std::path::PathBuf;
+use wordle_solver::{SESSION_VERSION, Session, Solver};
+
+fn words(list: &[&str]) -> Vec<String> {
+    list.iter().map(|w| w.to_string()).collect()
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("wordle-solver-{}-{}.json", name, std::process::id()))
+}
+
+#[test]
+fn saved_session_restores_history_and_filters() {
+    let dictionary = words(&["crate", "trace", "react", "slime", "mount", "brick"]);
+    let mut solver = Solver::new(dictionary.clone());
+    solver.apply("swill", "bbbbb");
+    solver.retain(|word| word.starts_with('c'));
+    let path = temp_path("roundtrip");
+    Session::capture(&solver, Some("words.txt")).save(&path).unwrap();
+
+    let session = Session::load(&path);
+    std::fs::remove_file(&path).unwrap();
+    let session = session.unwrap();
+    assert_eq!(session.version, SESSION_VERSION);
+    assert_eq!(session.dictionary.as_deref(), Some("words.txt"));
+
+    let mut restored = Solver::new(dictionary);
+    assert_eq!(session.restore(&mut restored), Ok(1));
+    assert_eq!(restored.filtered(), ["crate"]);
+    assert_eq!(restored.history(), solver.history());
+    assert!(restored.undo());
+    assert_eq!(restored.filtered(), ["crate", "trace", "react", "mount"]);
+}
+
+#[test]
+fn restore_replaces_a_list_of_the_same_length_but_other_words() {
+    let dictionary = words(&["crate", "trace", "react", "slime", "mount", "brick"]);
+    let mut solver = Solver::new(dictionary.clone());
+    solver.apply("swill", "bbbbb");
+    solver.remove("trace").unwrap();
+    solver.add("slime").unwrap();
+    assert_eq!(solver.filtered(), ["crate", "react", "slime", "mount"]);
+
+    let mut restored = Solver::new(dictionary);
+    assert_eq!(Session::capture(&solver, None).restore(&mut restored), Ok(4));
+    assert_eq!(restored.filtered(), solver.filtered());
+}
+
+#[test]
+fn other_version_is_rejected() {
+    let path = temp_path("version");
+    std::fs::write(
+        &path,
+        r#"{"version": 99, "dictionary": null, "history": [], "filtered": []}"#,
+    )
+    .unwrap();
+    let session = Session::load(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert!(session.is_err());
+}
+
+#[test]
+fn invalid_history_is_rejected_without_changes() {
+    let session = Session {
+        version: SESSION_VERSION,
+        dictionary: None,
+        history: vec![
+            ("crate".to_string(), "bbbbb".to_string()),
+            ("slime".to_string(), "bb".to_string()),
+        ],
+        filtered: Vec::new(),
+    };
+    let mut solver = Solver::new(words(&["crate", "slime"]));
+    assert!(session.restore(&mut solver).is_err());
+    assert_eq!(solver.filtered().len(), 2);
+    assert!(solver.history().is_empty());
+}
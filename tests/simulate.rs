@@ -0,0 +1,151 @@
+use wordle_solver::{
+    Difficulty, LetterCase, SimulationStats, Solver, Strategy, emoji_grid, emoji_row, format_difficulty, is_solved,
+    play, play_on, sample, simulate, simulate_from, simulate_on, simulate_with,
+};
+
+fn words(list: &[&str]) -> Vec<String> {
+    list.iter().map(|w| w.to_string()).collect()
+}
+
+#[test]
+fn play_ends_with_the_answer() {
+    let dictionary = words(&["crate", "trace", "react", "slime", "smile", "limes"]);
+    for answer in &dictionary {
+        let guesses = play(answer, &dictionary, &dictionary, None, Strategy::Entropy);
+        assert_eq!(guesses.last(), Some(answer));
+    }
+}
+
+#[test]
+fn play_starts_with_opener() {
+    let dictionary = words(&["crate", "trace", "react", "slime"]);
+    assert_eq!(
+        play("react", &dictionary, &dictionary, Some("slime"), Strategy::Entropy)[0],
+        "slime"
+    );
+}
+
+#[test]
+fn stats_histogram_and_failures() {
+    let mut stats = SimulationStats::default();
+    for guesses in [Some(1), Some(3), Some(3), Some(7), None] {
+        stats.record(guesses);
+    }
+    assert_eq!(stats.games, 5);
+    assert_eq!(stats.max_guesses, 7);
+    assert_eq!(stats.histogram, [1, 0, 2, 0, 0, 0, 2]);
+    assert_eq!(stats.failed(), 2);
+    assert_eq!(stats.unsolved, 1);
+    assert!((stats.mean() - 3.5).abs() < 1e-9);
+}
+
+#[test]
+fn simulate_plays_every_answer() {
+    let dictionary = words(&["crate", "trace", "react", "slime", "smile"]);
+    let stats = simulate(&dictionary, &dictionary, &dictionary, Strategy::Minimax);
+    assert_eq!(stats.games, 5);
+    assert_eq!(stats.failed(), 0);
+}
+
+#[test]
+fn sample_is_evenly_spaced() {
+    let dictionary = words(&["a", "b", "c", "d", "e", "f"]);
+    assert_eq!(sample(&dictionary, Some(3)), ["a", "c", "e"]);
+    assert_eq!(sample(&dictionary, None).len(), 6);
+    assert_eq!(sample(&dictionary, Some(10)).len(), 6);
+}
+
+#[test]
+fn emoji_grid_ends_with_green_row() {
+    let guesses = words(&["slime", "crate"]);
+    assert_eq!(
+        emoji_grid("crate", &guesses, LetterCase::Insensitive, 6),
+        "2/6\n⬛⬛⬛⬛🟩\n🟩🟩🟩🟩🟩"
+    );
+    assert_eq!(emoji_row("gybbG"), "🟩🟨⬛⬛🟩");
+}
+
+#[test]
+fn emoji_grid_marks_long_games_as_failed() {
+    let guesses = words(&["aaaaa", "bbbbb", "ccccc", "ddddd", "eeeee", "fffff", "crate"]);
+    assert!(emoji_grid("crate", &guesses, LetterCase::Insensitive, 6).starts_with("X/6\n"));
+    assert!(emoji_grid("crate", &guesses, LetterCase::Insensitive, 8).starts_with("7/8\n"));
+}
+
+#[test]
+fn simulate_with_reports_each_game() {
+    let dictionary = words(&["crate", "trace", "slime"]);
+    let mut games = Vec::new();
+    simulate_with(
+        &dictionary,
+        &dictionary,
+        &dictionary,
+        Strategy::Entropy,
+        |answer, guesses| games.push((answer.to_string(), guesses.to_vec())),
+    );
+    assert_eq!(games.len(), 3);
+    assert!(games.iter().all(|(answer, guesses)| guesses.last() == Some(answer)));
+}
+
+#[test]
+fn simulate_from_opens_every_game_with_the_given_word() {
+    let dictionary = words(&["crate", "trace", "slime", "night"]);
+    let mut openers = Vec::new();
+    let stats = simulate_from(
+        &dictionary,
+        &dictionary,
+        &dictionary,
+        Some("slime"),
+        Strategy::Entropy,
+        |_, guesses| openers.push(guesses[0].clone()),
+    );
+    assert_eq!(stats.games, 4);
+    assert_eq!(stats.histogram[0], 1);
+    assert!(openers.iter().all(|opener| opener == "slime"));
+}
+
+#[test]
+fn difficulty_labels_guess_counts() {
+    assert_eq!(Difficulty::from_guesses(2), Difficulty::Easy);
+    assert_eq!(Difficulty::from_guesses(3), Difficulty::Easy);
+    assert_eq!(Difficulty::from_guesses(4), Difficulty::Medium);
+    assert_eq!(Difficulty::from_guesses(5), Difficulty::Hard);
+
+    assert_eq!(
+        format_difficulty("crate", &words(&["raise", "track", "crate"]), LetterCase::Insensitive),
+        "crate: лёгкое (3 догадок)\nraise → track → crate"
+    );
+    assert!(
+        format_difficulty("crate", &words(&["raise", "track"]), LetterCase::Insensitive)
+            .starts_with("crate: трудное (не решено")
+    );
+}
+
+#[test]
+fn play_stops_on_an_all_green_pattern_even_if_the_case_differs() {
+    let dictionary = words(&["Polish", "barons"]);
+    let guesses = play("polish", &dictionary, &dictionary, None, Strategy::Entropy);
+    assert_eq!(guesses.last().map(String::as_str), Some("Polish"));
+    assert!(guesses.len() <= dictionary.len());
+}
+
+#[test]
+fn play_gives_up_when_the_answer_cannot_be_reached() {
+    let mut solver = Solver::new(words(&["Polish", "barons"]));
+    solver.set_case(LetterCase::Sensitive);
+    let played = play_on(&solver, "polish", None, Strategy::Entropy);
+    assert!(!played.is_empty() && played.len() <= 3, "{:?}", played);
+    assert!(!is_solved("polish", &played, LetterCase::Sensitive));
+
+    let stats = simulate_on(
+        &solver,
+        &words(&["polish", "barons"]),
+        None,
+        Strategy::Entropy,
+        |_, _| {},
+    );
+    assert_eq!(stats.games, 2);
+    assert_eq!(stats.unsolved, 1);
+    assert_eq!(stats.histogram[6], 1);
+    assert!((stats.mean() - stats.total_guesses as f64).abs() < 1e-9);
+}
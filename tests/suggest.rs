@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+use std::path::Path;
+use wordle_solver::{
+    PARALLEL_THRESHOLD, Solver, Strategy, entropy, expected_remaining, format_blended, format_split,
+    format_suggestions, load_dictionary, partition, suggest_entropy, suggest_minimax, weighted_entropy, worst_case,
+};
+
+fn words(list: &[&str]) -> Vec<String> {
+    list.iter().map(|w| w.to_string()).collect()
+}
+
+#[test]
+fn entropy_of_single_candidate_is_zero() {
+    assert_eq!(entropy("crate", &words(&["trace"])), 0.0);
+}
+
+#[test]
+fn distinct_patterns_give_full_entropy() {
+    let candidates = words(&["bat", "cat", "hat", "mat"]);
+    assert!((entropy("bch", &candidates) - 2.0).abs() < 1e-9);
+    assert!((entropy("xyz", &candidates)).abs() < 1e-9);
+}
+
+#[test]
+fn suggestions_sorted_by_descending_entropy() {
+    let candidates = words(&["bat", "cat", "hat", "mat"]);
+    let guesses = words(&["xyz", "bat", "bch"]);
+    let ranked = suggest_entropy(&candidates, &guesses);
+
+    assert_eq!(ranked[0].0, "bch");
+    assert_eq!(ranked.last().unwrap().0, "xyz");
+    assert!(ranked.windows(2).all(|w| w[0].1 >= w[1].1));
+}
+
+#[test]
+fn large_input_matches_sequential_scoring() {
+    let dictionary = load_dictionary(Path::new(env!("CARGO_MANIFEST_DIR")).join("wordle-La.txt")).unwrap();
+    let candidates = &dictionary[..PARALLEL_THRESHOLD + 100];
+    let guesses = &dictionary[..50];
+
+    let mut expected: Vec<(String, f64)> = guesses.iter().map(|g| (g.clone(), entropy(g, candidates))).collect();
+    expected.sort_by(|a, b| b.1.total_cmp(&a.1));
+    assert_eq!(suggest_entropy(candidates, guesses), expected);
+}
+
+#[test]
+fn best_guess_prefers_candidates_on_ties() {
+    let dictionary: Vec<String> = ["xyzzy", "crate", "trace"].iter().map(|w| w.to_string()).collect();
+    let answers: Vec<String> = ["crate"].iter().map(|w| w.to_string()).collect();
+    let solver = Solver::with_answers(dictionary, answers);
+    assert_eq!(solver.best_guess(), Some(("crate".to_string(), 0.0)));
+}
+
+#[test]
+fn best_guess_takes_highest_entropy() {
+    let dictionary: Vec<String> = ["bat", "cat", "hat", "mat", "bch"]
+        .iter()
+        .map(|w| w.to_string())
+        .collect();
+    let answers = dictionary[..4].to_vec();
+    let solver = Solver::with_answers(dictionary, answers);
+    assert_eq!(solver.best_guess().unwrap().0, "bch");
+}
+
+#[test]
+fn partition_groups_by_pattern() {
+    let candidates: Vec<String> = ["bat", "cat", "hat", "mat"].iter().map(|w| w.to_string()).collect();
+    let buckets = partition("hat", &candidates);
+    assert_eq!(buckets.len(), 2);
+    assert_eq!(buckets["bgg"], 3);
+    assert_eq!(buckets["ggg"], 1);
+    assert_eq!(format_split(&buckets), "bgg     3\nggg     1\nГрупп: 2, наибольшая: 3");
+}
+
+#[test]
+fn minimax_picks_guess_with_smallest_largest_bucket() {
+    let candidates = words(&["bat", "cat", "hat", "mat"]);
+    let guesses = words(&["xyz", "bat", "bch"]);
+
+    assert_eq!(worst_case("bat", &candidates), 3);
+    let ranked = suggest_minimax(&candidates, &guesses);
+    assert_eq!(
+        ranked,
+        vec![("bch".to_string(), 1), ("bat".to_string(), 3), ("xyz".to_string(), 4)]
+    );
+
+    let solver = Solver::with_answers(guesses, candidates);
+    assert_eq!(solver.best_guess_by(Strategy::Minimax).as_deref(), Some("bch"));
+}
+
+#[test]
+fn strategy_parses_from_name() {
+    assert_eq!("entropy".parse(), Ok(Strategy::Entropy));
+    assert_eq!("Minimax".parse(), Ok(Strategy::Minimax));
+    assert!("greedy".parse::<Strategy>().is_err());
+}
+
+#[test]
+fn suggestions_carry_all_metrics() {
+    let candidates = words(&["bat", "cat", "hat", "mat"]);
+    let solver = Solver::with_answers(words(&["xyz", "bat", "bch"]), candidates);
+
+    let by_entropy = solver.suggestions(Strategy::Entropy, 2);
+    assert_eq!(by_entropy.len(), 2);
+    assert_eq!(by_entropy[0].word, "bch");
+    assert!((by_entropy[0].entropy - 2.0).abs() < 1e-9);
+    assert_eq!(by_entropy[0].worst_case, 1);
+    assert!(!by_entropy[0].candidate);
+    assert_eq!(by_entropy[1].word, "bat");
+    assert_eq!(by_entropy[1].worst_case, 3);
+    assert!(by_entropy[1].candidate);
+
+    let by_minimax = solver.suggestions(Strategy::Minimax, 10);
+    assert_eq!(by_minimax.len(), 6);
+    let last = by_minimax.last().unwrap();
+    assert_eq!((last.word.as_str(), last.worst_case, last.entropy), ("xyz", 4, 0.0));
+}
+
+#[test]
+fn suggestion_table_aligns_columns() {
+    let solver = Solver::with_answers(words(&["xyz", "bat", "bch"]), words(&["bat", "cat", "hat", "mat"]));
+    let table = format_suggestions(&solver.suggestions(Strategy::Entropy, 3));
+    assert_eq!(
+        table,
+        "  №  слово     бит  группа  ответ\n  \
+           1  bch     2.000       1\n  \
+           2  bat     0.811       3  да\n  \
+           3  cat     0.811       3  да"
+    );
+}
+
+#[test]
+fn answers_only_ranks_just_the_remaining_candidates() {
+    let mut solver = Solver::with_answers(
+        words(&["chm", "bat", "cat", "hat", "mat"]),
+        words(&["bat", "cat", "hat", "mat"]),
+    );
+    assert_eq!(solver.best_guess_by(Strategy::Entropy).as_deref(), Some("chm"));
+    assert_eq!(solver.best_guess_by(Strategy::Minimax).as_deref(), Some("chm"));
+
+    solver.set_answers_only(true);
+    let suggestions = solver.suggestions(Strategy::Entropy, 10);
+    assert_eq!(suggestions.len(), 4);
+    assert!(suggestions.iter().all(|suggestion| suggestion.candidate));
+    assert_eq!(suggestions[0].entropy, entropy("bat", solver.filtered()));
+    assert_ne!(solver.best_guess_by(Strategy::Minimax).as_deref(), Some("chm"));
+}
+
+#[test]
+fn weighted_entropy_degrades_to_uniform_and_favors_splitting_frequent_words() {
+    let candidates = words(&["bat", "cat", "hat", "mat"]);
+    assert!((weighted_entropy("bch", &candidates, &[1.0; 4]) - entropy("bch", &candidates)).abs() < 1e-9);
+    assert!((weighted_entropy("bxx", &candidates, &[5.0; 4]) - entropy("bxx", &candidates)).abs() < 1e-9);
+    assert!(
+        weighted_entropy("cxx", &candidates, &[1.0, 101.0, 1.0, 1.0])
+            > weighted_entropy("bxx", &candidates, &[1.0, 101.0, 1.0, 1.0])
+    );
+
+    let mut solver = Solver::with_answers(words(&["bxx", "cxx"]), candidates);
+    solver.set_weighted(true);
+    let uniform = solver.suggest();
+    assert!((uniform[0].1 - uniform[1].1).abs() < 1e-9);
+    assert_eq!(solver.guess_entropy("bxx"), entropy("bxx", solver.filtered()));
+
+    solver.set_frequencies(HashMap::from([("cat".to_string(), 100)]));
+    assert_eq!(solver.best_guess().unwrap().0, "cat");
+    assert!(solver.guess_entropy("cxx") > solver.guess_entropy("bxx") + 0.1);
+    solver.set_weighted(false);
+    assert!((solver.suggest()[0].1 - uniform[0].1).abs() < 1e-9);
+}
+
+#[test]
+fn blended_score_dials_between_information_and_likely_answers() {
+    let guesses = words(&["crate", "slimy", "rates", "caret", "react", "brine"]);
+    let answers = words(&["crate", "caret", "react"]);
+    let mut solver = Solver::with_answers(guesses, answers);
+
+    let pure = solver.best_blended(1.0).unwrap();
+    assert_eq!(Some(pure.word.clone()), solver.best_guess().map(|(word, _)| word));
+    assert_eq!(pure.score, pure.entropy);
+
+    let likely = solver.best_blended(0.0).unwrap();
+    assert_eq!(likely.p_answer, 1.0);
+    assert!(solver.filtered().contains(&likely.word));
+
+    solver.set_frequencies(HashMap::from([("react".to_string(), 9), ("crate".to_string(), 4)]));
+    let frequent = solver.best_blended(0.0).unwrap();
+    assert_eq!(frequent.word, "react");
+    assert_eq!(frequent.score, 1.0);
+    assert!(format_blended(&frequent, 0.0).starts_with("Лучшая догадка: react\n  энтропия: "));
+}
+
+#[test]
+fn expected_remaining_is_mean_bucket_size_seen_by_the_answer() {
+    let candidates = words(&["crate", "trace", "slime", "night"]);
+    let buckets = partition("crate", &candidates);
+    assert_eq!(buckets.len(), 4);
+    assert_eq!(expected_remaining("crate", &candidates), 1.0);
+    assert_eq!(expected_remaining("zzzzz", &candidates), 4.0);
+    assert_eq!(expected_remaining("crate", &[]), 0.0);
+}
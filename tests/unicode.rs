@@ -0,0 +1,35 @@
+use wordle_solver::{Constraints, Solver, fold_letter, matches_pattern, parse_dictionary, score_guess};
+
+#[test]
+fn accented_letters_fold_case() {
+    assert_eq!(fold_letter('É'), 'é');
+    assert_eq!(fold_letter('Ñ'), 'ñ');
+    assert_eq!(fold_letter('ü'), 'ü');
+    assert_eq!(fold_letter('İ'), 'İ');
+}
+
+#[test]
+fn spanish_words_match_regardless_of_case() {
+    assert_eq!(score_guess("niño", "NIÑA"), "gggb");
+    assert!(matches_pattern("niño", "NIÑA", "gggb"));
+    assert!(matches_pattern("NIÑO", "niña", "gggb"));
+    assert!(!matches_pattern("nino", "niña", "gggb"));
+}
+
+#[test]
+fn french_dictionary_filters_by_accented_letters() {
+    let dictionary = parse_dictionary("étude\nÉlève\nétage\nfrère\nmère\ncrème\nlycée");
+    assert_eq!(
+        dictionary,
+        ["étude", "élève", "étage", "frère", "mère", "crème", "lycée"]
+    );
+
+    let mut solver = Solver::new(dictionary.into_iter().filter(|w| w.chars().count() == 5).collect());
+    solver.apply("ÉTAGE", "ggbbg");
+    assert_eq!(solver.filtered(), ["étude"]);
+
+    let mut constraints = Constraints::new();
+    constraints.add("frère", "bggbg");
+    assert!(constraints.matches("CRÈME"));
+    assert!(!constraints.matches("lycée"));
+}
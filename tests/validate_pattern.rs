@@ -0,0 +1,108 @@
+use wordle_solver::{PatternMap, matches_pattern, normalize_pattern, normalize_pattern_with, validate_pattern};
+
+#[test]
+fn accepts_valid_patterns_in_any_case() {
+    assert!(validate_pattern("crate", "ybbgy").is_ok());
+    assert!(validate_pattern("crate", "YBBGY").is_ok());
+}
+
+#[test]
+fn rejects_length_mismatch() {
+    assert!(validate_pattern("crate", "yy").unwrap_err().contains("длина"));
+    assert!(validate_pattern("crate", "ybbgyy").is_err());
+}
+
+#[test]
+fn rejects_invalid_characters() {
+    let err = validate_pattern("crate", "ybxgy").unwrap_err();
+    assert!(err.contains("'x'"));
+    assert!(err.contains('3'));
+}
+
+#[test]
+fn emoji_are_normalized_to_letters() {
+    assert_eq!(normalize_pattern("🟨⬛⬛🟩🟨"), "ybbgy");
+    assert_eq!(normalize_pattern("⬜🟩⬛️"), "bgb");
+    assert_eq!(normalize_pattern("y⬛b🟩Y"), "ybbgY");
+    assert!(validate_pattern("crate", &normalize_pattern("🟨⬛b🟩y")).is_ok());
+}
+
+#[test]
+fn unknown_emoji_still_rejected() {
+    assert!(validate_pattern("crate", &normalize_pattern("🟨⬛🟥🟩🟨")).is_err());
+}
+
+#[test]
+fn digits_are_normalized_to_letters() {
+    assert_eq!(normalize_pattern("21001"), "gybby");
+    assert!(validate_pattern("crate", &normalize_pattern("21001")).is_ok());
+    assert_eq!(normalize_pattern("21001"), normalize_pattern("gybby"));
+}
+
+#[test]
+fn digit_pattern_filters_like_letters() {
+    let dictionary = ["trace", "cater", "react", "crate", "caret"];
+    for word in dictionary {
+        assert_eq!(
+            matches_pattern(word, "crate", &normalize_pattern("21001")),
+            matches_pattern(word, "crate", "gybby"),
+            "{}",
+            word
+        );
+    }
+}
+
+#[test]
+fn mixed_digits_and_letters_are_rejected() {
+    let err = validate_pattern("crate", &normalize_pattern("2y00b")).unwrap_err();
+    assert!(err.contains("смешивать"), "{}", err);
+    assert!(validate_pattern("crate", &normalize_pattern("🟩1⬛⬛0")).is_err());
+    assert!(validate_pattern("crate", &normalize_pattern("21003")).is_err());
+}
+
+#[test]
+fn custom_pattern_map_translates_symbols() {
+    let map: PatternMap = "green=+,yellow=?,black=.".parse().unwrap();
+    assert_eq!(normalize_pattern_with("?..+?", &map), "ybbgy");
+    assert_eq!(normalize_pattern_with("🟨⬛.+?", &map), "ybbgy");
+    assert!(validate_pattern("crate", &normalize_pattern_with("?.x+?", &map)).is_err());
+
+    let russian: PatternMap = "green=з, yellow=ж, black=ч".parse().unwrap();
+    assert_eq!(normalize_pattern_with("ЖччЗж", &russian), "ybbgy");
+    assert_eq!(normalize_pattern_with("ybbgy", &PatternMap::default()), "ybbgy");
+}
+
+#[test]
+fn pattern_map_rejects_clashing_or_malformed_symbols() {
+    assert!(
+        "green=+,yellow=+"
+            .parse::<PatternMap>()
+            .unwrap_err()
+            .contains("различаться")
+    );
+    assert!("green=y".parse::<PatternMap>().is_err());
+    assert!("black=З,green=з".parse::<PatternMap>().is_err());
+    assert!("red=+".parse::<PatternMap>().unwrap_err().contains("red"));
+    assert!("green=++".parse::<PatternMap>().is_err());
+    assert!("green".parse::<PatternMap>().is_err());
+    assert_eq!("yellow=?".parse::<PatternMap>().unwrap().green, 'g');
+}
+
+#[test]
+fn impossible_combinations_are_explained() {
+    let err = validate_pattern("speed", "bbbyb").unwrap_err();
+    assert!(
+        err.contains("буква E жёлтая в позиции 4, но чёрная в позиции 3"),
+        "{}",
+        err
+    );
+    assert!(validate_pattern("speed", "bbybb").is_ok());
+    assert!(validate_pattern("speed", "bbyyb").is_ok());
+
+    let err = validate_pattern("crate", "ggggy").unwrap_err();
+    assert!(err.contains("буква E отмечена жёлтой 1 раз"), "{}", err);
+    assert!(validate_pattern("eerie", "yybbb").is_ok());
+    assert!(validate_pattern("eerie", "yyggy").is_err());
+    assert!(validate_pattern("crate", "yyyyy").is_ok());
+    assert!(validate_pattern("CRATE", "GGGGY").is_err());
+}
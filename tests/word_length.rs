@@ -0,0 +1,25 @@
+use wordle_solver::{filter_by_length, modal_length, validate_guess};
+
+fn words(list: &[&str]) -> Vec<String> {
+    list.iter().map(|w| w.to_string()).collect()
+}
+
+#[test]
+fn modal_length_picks_most_common() {
+    assert_eq!(modal_length(&words(&["crate", "trace", "cats", "react"])), Some(5));
+    assert_eq!(modal_length(&words(&["cats", "dogs", "crate"])), Some(4));
+    assert_eq!(modal_length(&[]), None);
+}
+
+#[test]
+fn filter_keeps_only_requested_length() {
+    let mut dictionary = words(&["crate", "cats", "planet", "trace"]);
+    filter_by_length(&mut dictionary, 5);
+    assert_eq!(dictionary, ["crate", "trace"]);
+}
+
+#[test]
+fn guess_of_wrong_length_rejected() {
+    assert!(validate_guess("crate", 5).is_ok());
+    assert!(validate_guess("crates", 5).is_err());
+}